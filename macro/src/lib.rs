@@ -25,6 +25,9 @@ pub fn derive_style_parser(input: TokenStream) -> TokenStream {
 
     quote! {
         impl #impl_generics #name #ty_generics #where_clause {
+            /// All supported css properties
+            pub const PROPERTIES: &'static [&'static str] = &[ #( #properties ),* ];
+
             pub fn from_cow((key, value): (&std::borrow::Cow<'_, str>, &std::borrow::Cow<'_, str>)) -> Option<Self> {
                 match key.as_ref() {
                     #( #properties => Some(Self::#idents(#parsers(value)?)), )*
@@ -32,9 +35,19 @@ pub fn derive_style_parser(input: TokenStream) -> TokenStream {
                 }
             }
 
+            fn apply_style_value(s: floem::style::Style, key: &str, keyword: crate::declaration::CssWideKeyword) -> floem::style::Style {
+                match key {
+                    #( #properties => s #( .set_style_value(#style_classes, keyword.style_value(#style_classes)) )*, )*
+                    invalid => {
+                        log::error!("Invalid keyword key '{invalid}'");
+                        s
+                    },
+                }
+            }
+
             fn apply_transition(s: floem::style::Style, key: &str, t: floem::style::Transition) -> floem::style::Style {
                 match key {
-                    #( #properties => s.transition(#style_classes, t), )*
+                    #( #properties => s #( .transition(#style_classes, t.clone()) )*, )*
                     invalid => {
                         log::error!("Invalid transition key '{invalid}'");
                         s
@@ -1,7 +1,7 @@
 use proc_macro2::Span;
 use quote::ToTokens;
 use smallvec::SmallVec;
-use syn::{punctuated::Punctuated, token::Comma, Ident, LitStr, Variant};
+use syn::{punctuated::Punctuated, token::Comma, Ident, LitStr, Token, Variant};
 
 const SIZE: usize = 64;
 
@@ -9,7 +9,7 @@ pub struct ParsedVariants<'a> {
     pub(crate) idents: SmallVec<[&'a Ident; SIZE]>,
     pub(crate) properties: SmallVec<[LitStr; SIZE]>,
     pub(crate) parsers: SmallVec<[proc_macro2::Ident; SIZE]>,
-    pub(crate) style_classes: SmallVec<[Ident; SIZE]>,
+    pub(crate) style_classes: SmallVec<[Vec<Ident>; SIZE]>,
 }
 
 impl<'a> ParsedVariants<'a> {
@@ -34,7 +34,7 @@ pub struct ParsedVariant<'a> {
     ident: &'a Ident,
     property: LitStr,
     parser: proc_macro2::Ident,
-    style_class: Ident,
+    style_class: Vec<Ident>,
 }
 
 impl<'a> From<&'a Punctuated<Variant, Comma>> for ParsedVariants<'a> {
@@ -59,18 +59,24 @@ pub fn parse_enum_variant(variant: &Variant) -> ParsedVariant<'_> {
     }
 }
 
-fn find_style_class(variant: &Variant) -> Ident {
+/// Style classes are comma separated list of idents, all of them
+/// get the transition of the property
+fn find_style_class(variant: &Variant) -> Vec<Ident> {
     let style_class_attr = variant
         .attrs
         .iter()
         .find(|a| a.path().is_ident("style_class"))
         .unwrap_or_else(|| panic!("Missing style_class attribute for {}", variant.ident));
-    style_class_attr.parse_args::<Ident>().unwrap_or_else(|e| {
-        panic!(
-            "style_class attribute must be ident: {}\n{e}",
-            style_class_attr.to_token_stream()
-        )
-    })
+    style_class_attr
+        .parse_args_with(Punctuated::<Ident, Token![,]>::parse_separated_nonempty)
+        .unwrap_or_else(|e| {
+            panic!(
+                "style_class attribute must be list of idents: {}\n{e}",
+                style_class_attr.to_token_stream()
+            )
+        })
+        .into_iter()
+        .collect()
 }
 
 fn find_property(variant: &Variant) -> LitStr {
@@ -1,4 +1,5 @@
 use crate::lexer::Token;
+use crate::parser::{AtRule, Selector};
 
 pub struct SyntaxError<'a> {
     pub line: usize,
@@ -31,15 +32,27 @@ pub fn analyze_tokens<'a>(tokens: &[Token<'a>], input: &'a str) -> Vec<SyntaxErr
     let mut errors: Vec<SyntaxError> = Vec::with_capacity(tokens.len());
     let mut tokens = tokens.iter().peekable();
     let lines = input.lines().collect::<Vec<_>>();
-    loop {
-        let Some(token) = tokens.next() else {
-            break;
-        };
-
+    while let Some(token) = tokens.next() {
         match token {
-            Token::Selector { line, value } => {
+            Token::Selector {
+                line,
+                value: selector,
+            } => {
                 let line = *line;
-                let (column, value) = find_line_with_value(&lines, line, value);
+                let (column, value) = find_line_with_value(&lines, line, selector);
+                if selector
+                    .split(',')
+                    .map(str::trim)
+                    .any(|s| Selector::try_from(s).is_err())
+                {
+                    let error = SyntaxError {
+                        line,
+                        column,
+                        error: "Unknown pseudo-class or pseudo-element",
+                        value,
+                    };
+                    errors.push(error);
+                }
                 if let Some(peek) = tokens.peek() {
                     if !matches!(peek, Token::BlockOpen { .. } | Token::Selector { .. }) {
                         let error = SyntaxError {
@@ -60,6 +73,25 @@ pub fn analyze_tokens<'a>(tokens: &[Token<'a>], input: &'a str) -> Vec<SyntaxErr
                     errors.push(error);
                 }
             }
+            Token::AtRule {
+                line,
+                value: at_rule,
+            } => {
+                let line = *line;
+                // Prelude can continue on following lines, first line is enough for column
+                let first = at_rule.lines().next().unwrap_or_default();
+                let value = lines.get(line - 1).copied().unwrap_or_default();
+                let column = value.find(first).unwrap_or(0);
+                if AtRule::parse_str(at_rule).is_none() {
+                    let error = SyntaxError {
+                        line,
+                        column,
+                        error: "Unsupported at-rule",
+                        value,
+                    };
+                    errors.push(error);
+                }
+            }
             Token::Property { line, value } => {
                 if let Some(peek) = tokens.peek() {
                     if !matches!(peek, Token::Colon { .. }) {
@@ -134,3 +166,18 @@ pub fn analyze_tokens<'a>(tokens: &[Token<'a>], input: &'a str) -> Vec<SyntaxErr
 
     errors
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::analyze;
+
+    #[test]
+    fn at_rule_block_on_next_line() {
+        let input = "@media (prefers-contrast: more)\n{\n    label {\n        color: red;\n    }\n}\n@media print\n{\n}\n";
+        let errors = analyze(input);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line, 7);
+        assert_eq!(errors[0].column, 1);
+        assert_eq!(errors[0].error, "Unsupported at-rule");
+    }
+}
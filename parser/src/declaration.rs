@@ -1,39 +1,301 @@
+use std::rc::Rc;
 use std::time::Duration;
 
-use floem::peniko::{Brush, Color};
+use floem::easing::{Bezier, Easing, Linear, Spring, Step, StepPosition};
+use floem::kurbo::{Point, Stroke};
+use floem::peniko::{Brush, Color, ColorStop, Gradient};
 use floem::prop;
 use floem::style::{
     AlignContentProp, AlignItemsProp, AlignSelf, AspectRatio, Background, BorderBottom,
     BorderColor, BorderLeft, BorderRadius, BorderRight, BorderTop, BoxShadow, BoxShadowProp,
     ColGap, Cursor, CursorColor, CursorStyle, DisplayProp, FlexBasis, FlexDirectionProp, FlexGrow,
-    FlexShrink, FlexWrapProp, FontFamily, FontSize, FontStyle, FontWeight, Height, InsetBottom,
+    FlexShrink, FlexWrapProp, FontFamily, FontSize, FontStyle, FontWeight, GridAutoColumns,
+    GridAutoRows, GridColumn, GridRow, GridTemplateColumns, GridTemplateRows, Height, InsetBottom,
     InsetLeft, InsetRight, InsetTop, JustifyContentProp, JustifySelf, LineHeight, MarginBottom,
     MarginLeft, MarginRight, MarginTop, MaxHeight, MaxWidth, MinHeight, MinWidth, Outline,
-    OutlineColor, PaddingBottom, PaddingLeft, PaddingRight, PaddingTop, PositionProp, RowGap,
-    Selectable, Style, StylePropValue, TextColor, TextOverflow, TextOverflowProp, Transition,
-    Width, ZIndex,
+    OutlineColor, PaddingBottom, PaddingLeft, PaddingRight, PaddingTop, PositionProp, Rotation,
+    RowGap, ScaleX, ScaleY, Selectable, StrokeWrap, Style, StyleProp, StylePropValue, StyleValue,
+    TextColor, TextOverflow, TextOverflowProp, Transition, TranslateX, TranslateY, Width, ZIndex,
 };
+use floem::taffy::style_helpers::{line, span};
 use floem::taffy::{
-    AlignContent, AlignItems, Display, FlexDirection, FlexWrap, JustifyContent, Position,
+    AlignContent, AlignItems, Display, FlexDirection, FlexWrap, GridPlacement, GridTrackRepetition,
+    JustifyContent, LengthPercentage, Line, MaxTrackSizingFunction, MinMax, MinTrackSizingFunction,
+    NonRepeatedTrackSizingFunction, Position, TrackSizingFunction,
 };
-use floem::text::Weight;
+use floem::text::{LineHeightValue, Weight};
 use floem::unit::{Pct, Px, PxPct, PxPctAuto};
-use floem::views::scroll::Border;
+use floem::views::editor::gutter::{DimColor, LeftOfCenterPadding, RightOfCenterPadding};
+use floem::views::editor::{
+    CurrentLineColor, IndentGuideColor, ScrollBeyondLastLine, SelectionColor, ShowIndentGuide,
+    VisibleWhitespaceColor,
+};
+use floem::views::SvgColor;
 use floem_css_macros::StyleParser;
 use smallvec::SmallVec;
 
+use crate::{logical, media, units};
+
+/// Line style of border or outline
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BorderStyle {
+    None,
+    #[default]
+    Solid,
+    Dashed,
+    Dotted,
+}
+
+impl BorderStyle {
+    /// Stroke for border side or outline of given `width`
+    #[must_use]
+    pub fn stroke(self, width: f64) -> Stroke {
+        match self {
+            Self::None => Stroke::new(0.0),
+            Self::Solid => Stroke::new(width),
+            Self::Dashed => Stroke::new(width).with_dashes(0.0, [width * 3.0, width * 2.0]),
+            Self::Dotted => Stroke::new(width).with_dashes(0.0, [width, width]),
+        }
+    }
+}
+
+/// Parsed `<width> <style> <color>` border or outline shorthand,
+/// values can be in any order
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct BorderDef {
     width: Option<Px>,
+    style: Option<BorderStyle>,
     color: Option<Color>,
 }
 
-impl StylePropValue for BorderDef {}
+/// Value for each side, expanded from css 1-4 value shorthand
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sides<T> {
+    pub top: T,
+    pub right: T,
+    pub bottom: T,
+    pub left: T,
+}
+
+/// Transition of one property, `property` is css property name or `all`
+#[derive(Clone, Debug)]
+pub struct TransitionDef {
+    pub property: String,
+    pub duration: Duration,
+    pub easing: Rc<dyn Easing>,
+    pub delay: Duration,
+}
+
+impl PartialEq for TransitionDef {
+    fn eq(&self, other: &Self) -> bool {
+        self.property == other.property
+            && self.duration == other.duration
+            && Rc::ptr_eq(&self.easing, &other.easing)
+            && self.delay == other.delay
+    }
+}
+
+/// Transitions of a rule. `transition-delay` overrides delays of the `transition`
+/// list, repeating the delays if there are less of them than transitions.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TransitionList {
+    pub transitions: SmallVec<[TransitionDef; 2]>,
+    pub delays: Option<SmallVec<[Duration; 2]>>,
+}
+
+impl StylePropValue for TransitionList {}
+
+impl TransitionList {
+    /// Sets transition of every listed property to `s`
+    fn apply(&self, mut s: Style) -> Style {
+        for (i, def) in self.transitions.iter().enumerate() {
+            let delay = self
+                .delays
+                .as_ref()
+                .and_then(|d| d.get(i % d.len()).copied())
+                .unwrap_or(def.delay);
+            let transition = delayed_transition(def, delay);
+            if def.property == "all" {
+                for property in Declaration::PROPERTIES {
+                    if !property.starts_with("transition") {
+                        s = Declaration::apply_transition(s, property, transition.clone());
+                    }
+                }
+            } else {
+                s = Declaration::apply_transition(s, &def.property, transition);
+            }
+        }
+        s
+    }
+}
+
+fn delayed_transition(def: &TransitionDef, delay: Duration) -> Transition {
+    if delay.is_zero() {
+        return Transition {
+            duration: def.duration,
+            easing: def.easing.clone(),
+        };
+    }
+    let duration = def.duration + delay;
+    let easing = Delayed {
+        delay: delay.as_secs_f64() / duration.as_secs_f64(),
+        easing: def.easing.clone(),
+    };
+    Transition::new(duration, easing)
+}
+
+/// Floem transitions have no delay, so the delay is part of the duration and
+/// easing stays at start until the delay has passed
+#[derive(Debug)]
+struct Delayed {
+    /// Fraction of the whole duration
+    delay: f64,
+    easing: Rc<dyn Easing>,
+}
+
+impl Delayed {
+    fn local_time(&self, time: f64) -> f64 {
+        if self.delay >= 1.0 {
+            return if time >= 1.0 { 1.0 } else { 0.0 };
+        }
+        ((time - self.delay) / (1.0 - self.delay)).max(0.0)
+    }
+}
+
+impl Easing for Delayed {
+    fn eval(&self, time: f64) -> f64 {
+        self.easing.eval(self.local_time(time))
+    }
+
+    fn velocity(&self, time: f64) -> Option<f64> {
+        self.easing.velocity(self.local_time(time))
+    }
+
+    fn finished(&self, time: f64) -> bool {
+        time >= self.delay && self.easing.finished(self.local_time(time))
+    }
+}
+
+prop!(pub TransitionProp: TransitionList {} = TransitionList::default());
+
+/// Expanded `font` shorthand, omitted style, weight and line height are reset
+#[derive(Clone, Debug, PartialEq)]
+pub struct FontDef {
+    pub style: floem::text::Style,
+    pub weight: Weight,
+    pub size: Px,
+    /// `None` is Floem's default line height
+    pub line_height: Option<LineHeightValue>,
+    pub family: String,
+}
+
+/// Expanded `flex` shorthand
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FlexDef {
+    pub grow: f32,
+    pub shrink: f32,
+    pub basis: PxPctAuto,
+}
+
+impl Default for FlexDef {
+    fn default() -> Self {
+        Self {
+            grow: 0.0,
+            shrink: 1.0,
+            basis: PxPctAuto::Auto,
+        }
+    }
+}
+
+/// Parsed `transform` functions. Floem applies translation first and then
+/// scale and rotation around the center of the element.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TransformDef {
+    pub translate: (f64, f64),
+    /// Scale factors, 1.0 is the original size
+    pub scale: (f64, f64),
+    /// Rotation in degrees
+    pub rotate: f64,
+}
+
+impl Default for TransformDef {
+    fn default() -> Self {
+        Self {
+            translate: (0.0, 0.0),
+            scale: (1.0, 1.0),
+            rotate: 0.0,
+        }
+    }
+}
+
+/// Easing function parsed from css timing function
+#[derive(Clone, Debug)]
+pub struct TimingFunction(pub Rc<dyn Easing>);
+
+impl Default for TimingFunction {
+    fn default() -> Self {
+        Self(Rc::new(Bezier::ease()))
+    }
+}
+
+impl PartialEq for TimingFunction {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Easing for TimingFunction {
+    fn eval(&self, time: f64) -> f64 {
+        self.0.eval(time)
+    }
+
+    fn velocity(&self, time: f64) -> Option<f64> {
+        self.0.velocity(time)
+    }
+
+    fn finished(&self, time: f64) -> bool {
+        self.0.finished(time)
+    }
+}
+
+impl StylePropValue for TimingFunction {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IterationCount {
+    Infinite,
+    Count(usize),
+}
+
+impl StylePropValue for IterationCount {}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AnimationDirection {
+    #[default]
+    Normal,
+    Reverse,
+    Alternate,
+    AlternateReverse,
+}
+
+impl StylePropValue for AnimationDirection {}
 
-prop!(pub Padding: PxPctAuto {} = PxPctAuto::Px(0.0));
-prop!(pub Margin: PxPctAuto {} = PxPctAuto::Px(0.0));
-prop!(pub TransitionProp: f64 {} = 0.0);
-prop!(pub BorderProp: BorderDef {} = BorderDef::default());
+/// Parsed `animation` shorthand, unset parts have their initial values
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AnimationDef {
+    pub name: Option<String>,
+    pub duration: Duration,
+    pub timing_function: TimingFunction,
+    pub delay: Duration,
+    pub iteration_count: Option<IterationCount>,
+    pub direction: AnimationDirection,
+}
+
+prop!(pub AnimationNameProp: Option<String> {} = None);
+prop!(pub AnimationDurationProp: Duration {} = Duration::ZERO);
+prop!(pub AnimationTimingFunctionProp: TimingFunction {} = TimingFunction::default());
+prop!(pub AnimationDelayProp: Duration {} = Duration::ZERO);
+prop!(pub AnimationIterationCountProp: IterationCount {} = IterationCount::Count(1));
+prop!(pub AnimationDirectionProp: AnimationDirection {} = AnimationDirection::Normal);
 
 #[derive(StyleParser)]
 pub enum Declaration {
@@ -102,6 +364,16 @@ pub enum Declaration {
     #[style_class(FlexBasis)]
     FlexBasis(PxPctAuto),
 
+    #[property("flex")]
+    #[parser("parse_flex")]
+    #[style_class(FlexGrow, FlexShrink, FlexBasis)]
+    Flex(FlexDef),
+
+    #[property("flex-flow")]
+    #[parser("parse_flex_flow")]
+    #[style_class(FlexDirectionProp, FlexWrapProp)]
+    FlexFlow((FlexDirection, FlexWrap)),
+
     #[property("justify-content")]
     #[parser("parse_justify_content")]
     #[style_class(JustifyContentProp)]
@@ -127,60 +399,140 @@ pub enum Declaration {
     #[style_class(AlignSelf)]
     AlignSelf(AlignItems),
 
+    #[property("place-content")]
+    #[parser("parse_place_content")]
+    #[style_class(AlignContentProp, JustifyContentProp)]
+    PlaceContent((AlignContent, JustifyContent)),
+
+    #[property("place-items")]
+    #[parser("parse_place_items")]
+    #[style_class(AlignItemsProp)]
+    PlaceItems(AlignItems),
+
+    #[property("place-self")]
+    #[parser("parse_place_self")]
+    #[style_class(AlignSelf, JustifySelf)]
+    PlaceSelf((AlignItems, AlignItems)),
+
+    #[property("grid-template-columns")]
+    #[parser("parse_grid_template")]
+    #[style_class(GridTemplateColumns)]
+    GridTemplateColumns(Vec<TrackSizingFunction>),
+
+    #[property("grid-template-rows")]
+    #[parser("parse_grid_template")]
+    #[style_class(GridTemplateRows)]
+    GridTemplateRows(Vec<TrackSizingFunction>),
+
+    #[property("grid-auto-columns")]
+    #[parser("parse_grid_auto_tracks")]
+    #[style_class(GridAutoColumns)]
+    GridAutoColumns(Vec<NonRepeatedTrackSizingFunction>),
+
+    #[property("grid-auto-rows")]
+    #[parser("parse_grid_auto_tracks")]
+    #[style_class(GridAutoRows)]
+    GridAutoRows(Vec<NonRepeatedTrackSizingFunction>),
+
+    #[property("grid-column")]
+    #[parser("parse_grid_line")]
+    #[style_class(GridColumn)]
+    GridColumn(Line<GridPlacement>),
+
+    #[property("grid-row")]
+    #[parser("parse_grid_line")]
+    #[style_class(GridRow)]
+    GridRow(Line<GridPlacement>),
+
     #[property("border")]
     #[parser("parse_border")]
-    #[style_class(BorderProp)]
+    #[style_class(BorderTop, BorderRight, BorderBottom, BorderLeft, BorderColor)]
     Border(BorderDef),
 
     #[property("border-width")]
-    #[parser("parse_px")]
-    #[style_class(Border)]
-    BorderWidth(Px),
+    #[parser("parse_border_width")]
+    #[style_class(BorderTop, BorderRight, BorderBottom, BorderLeft)]
+    BorderWidth(Sides<Px>),
+
+    #[property("border-style")]
+    #[parser("parse_border_styles")]
+    #[style_class(BorderTop, BorderRight, BorderBottom, BorderLeft)]
+    BorderStyle(Sides<BorderStyle>),
 
     #[property("border-left")]
-    #[parser("parse_px")]
+    #[parser("parse_border_side")]
     #[style_class(BorderLeft)]
-    BorderLeft(Px),
+    BorderLeft(BorderDef),
 
     #[property("border-top")]
-    #[parser("parse_px")]
+    #[parser("parse_border_side")]
     #[style_class(BorderTop)]
-    BorderTop(Px),
+    BorderTop(BorderDef),
 
     #[property("border-right")]
-    #[parser("parse_px")]
+    #[parser("parse_border_side")]
     #[style_class(BorderRight)]
-    BorderRight(Px),
+    BorderRight(BorderDef),
 
     #[property("border-bottom")]
+    #[parser("parse_border_side")]
+    #[style_class(BorderBottom)]
+    BorderBottom(BorderDef),
+
+    #[property("border-left-width")]
+    #[parser("parse_px")]
+    #[style_class(BorderLeft)]
+    BorderLeftWidth(Px),
+
+    #[property("border-top-width")]
+    #[parser("parse_px")]
+    #[style_class(BorderTop)]
+    BorderTopWidth(Px),
+
+    #[property("border-right-width")]
+    #[parser("parse_px")]
+    #[style_class(BorderRight)]
+    BorderRightWidth(Px),
+
+    #[property("border-bottom-width")]
     #[parser("parse_px")]
     #[style_class(BorderBottom)]
-    BorderBottom(Px),
+    BorderBottomWidth(Px),
 
     #[property("border-radius")]
-    #[parser("parse_px_pct")]
+    #[parser("parse_border_radius")]
     #[style_class(BorderRadius)]
     BorderRadius(PxPct),
 
+    #[property("outline")]
+    #[parser("parse_border")]
+    #[style_class(Outline, OutlineColor)]
+    Outline(BorderDef),
+
+    #[property("outline-width")]
+    #[parser("parse_px")]
+    #[style_class(Outline)]
+    OutlineWidth(Px),
+
+    #[property("outline-style")]
+    #[parser("parse_border_style")]
+    #[style_class(Outline)]
+    OutlineStyle(BorderStyle),
+
     #[property("outline-color")]
     #[parser("parse_color")]
     #[style_class(OutlineColor)]
     OutlineColor(Color),
 
-    #[property("outline")]
-    #[parser("parse_px")]
-    #[style_class(Outline)]
-    Outline(Px),
-
     #[property("border-color")]
-    #[parser("parse_color")]
+    #[parser("parse_border_colors")]
     #[style_class(BorderColor)]
     BorderColor(Color),
 
     #[property("padding")]
-    #[parser("parse_px_pct")]
-    #[style_class(Padding)]
-    Padding(PxPct),
+    #[parser("parse_padding")]
+    #[style_class(PaddingTop, PaddingRight, PaddingBottom, PaddingLeft)]
+    Padding(Sides<PxPct>),
 
     #[property("padding-left")]
     #[parser("parse_px_pct")]
@@ -203,9 +555,9 @@ pub enum Declaration {
     PaddingBottom(PxPct),
 
     #[property("margin")]
-    #[parser("parse_pxpctauto")]
-    #[style_class(Margin)]
-    Margin(PxPctAuto),
+    #[parser("parse_margin")]
+    #[style_class(MarginTop, MarginRight, MarginBottom, MarginLeft)]
+    Margin(Sides<PxPctAuto>),
 
     #[property("margin-left")]
     #[parser("parse_pxpctauto")]
@@ -227,6 +579,11 @@ pub enum Declaration {
     #[style_class(MarginBottom)]
     MarginBottom(PxPctAuto),
 
+    #[property("inset")]
+    #[parser("parse_inset")]
+    #[style_class(InsetTop, InsetRight, InsetBottom, InsetLeft)]
+    Inset(Sides<PxPctAuto>),
+
     #[property("left")]
     #[parser("parse_pxpctauto")]
     #[style_class(InsetLeft)]
@@ -262,23 +619,38 @@ pub enum Declaration {
     #[style_class(TextColor)]
     Color(Color),
 
+    #[property("fill")]
+    #[parser("parse_fill")]
+    #[style_class(SvgColor)]
+    Fill(Option<Brush>),
+
     #[property("background-color")]
     #[parser("parse_color")]
     #[style_class(Background)]
     BackgroundColor(Color),
 
+    #[property("background")]
+    #[parser("parse_background")]
+    #[style_class(Background)]
+    Background(Brush),
+
+    #[property("background-image")]
+    #[parser("parse_background_image")]
+    #[style_class(Background)]
+    BackgroundImage(Option<Brush>),
+
     #[property("box-shadow")]
     #[parser("parse_box_shadow")]
     #[style_class(BoxShadowProp)]
     BoxShadow(BoxShadow),
 
     #[property("font-size")]
-    #[parser("parse_px")]
+    #[parser("parse_font_size")]
     #[style_class(FontSize)]
     FontSize(Px),
 
     #[property("font-family")]
-    #[parser("to_owned")]
+    #[parser("parse_font_family")]
     #[style_class(FontFamily)]
     FontFamily(String),
 
@@ -303,9 +675,14 @@ pub enum Declaration {
     TextOverflow(TextOverflow),
 
     #[property("line-height")]
-    #[parser("parse_f32")]
+    #[parser("parse_line_height")]
     #[style_class(LineHeight)]
-    LineHeight(f32),
+    LineHeight(Option<LineHeightValue>),
+
+    #[property("font")]
+    #[parser("parse_font")]
+    #[style_class(FontStyle, FontWeight, FontSize, LineHeight, FontFamily)]
+    Font(FontDef),
 
     #[property("aspect-ratio")]
     #[parser("parse_f32")]
@@ -324,18 +701,241 @@ pub enum Declaration {
 
     #[property("gap")]
     #[parser("parse_gap")]
-    #[style_class(RowGap)]
+    #[style_class(RowGap, ColGap)]
     Gap((PxPct, Option<PxPct>)),
 
+    #[property("transform")]
+    #[parser("parse_transform")]
+    #[style_class(TranslateX, TranslateY, ScaleX, ScaleY, Rotation)]
+    Transform(TransformDef),
+
+    #[property("translate")]
+    #[parser("parse_translate")]
+    #[style_class(TranslateX, TranslateY)]
+    Translate((f64, f64)),
+
+    #[property("scale")]
+    #[parser("parse_scale")]
+    #[style_class(ScaleX, ScaleY)]
+    Scale((f64, f64)),
+
+    #[property("rotate")]
+    #[parser("parse_rotate")]
+    #[style_class(Rotation)]
+    Rotate(f64),
+
     #[property("transition")]
     #[parser("parse_transition")]
     #[style_class(TransitionProp)]
-    Transition((String, Transition)),
+    Transition(TransitionList),
+
+    #[property("animation")]
+    #[parser("parse_animation")]
+    #[style_class(
+        AnimationNameProp,
+        AnimationDurationProp,
+        AnimationTimingFunctionProp,
+        AnimationDelayProp,
+        AnimationIterationCountProp,
+        AnimationDirectionProp
+    )]
+    Animation(AnimationDef),
+
+    #[property("animation-name")]
+    #[parser("parse_animation_name")]
+    #[style_class(AnimationNameProp)]
+    AnimationName(Option<String>),
+
+    #[property("animation-duration")]
+    #[parser("parse_duration")]
+    #[style_class(AnimationDurationProp)]
+    AnimationDuration(Duration),
+
+    #[property("animation-timing-function")]
+    #[parser("parse_timing_function")]
+    #[style_class(AnimationTimingFunctionProp)]
+    AnimationTimingFunction(TimingFunction),
+
+    #[property("animation-delay")]
+    #[parser("parse_duration")]
+    #[style_class(AnimationDelayProp)]
+    AnimationDelay(Duration),
+
+    #[property("animation-iteration-count")]
+    #[parser("parse_iteration_count")]
+    #[style_class(AnimationIterationCountProp)]
+    AnimationIterationCount(IterationCount),
+
+    #[property("animation-direction")]
+    #[parser("parse_animation_direction")]
+    #[style_class(AnimationDirectionProp)]
+    AnimationDirection(AnimationDirection),
+
+    #[property("transition-delay")]
+    #[parser("parse_transition_delay")]
+    #[style_class(TransitionProp)]
+    TransitionDelay(SmallVec<[Duration; 2]>),
 
     #[property("user-select")]
     #[parser("parse_user_select")]
     #[style_class(Selectable)]
     UserSelect(bool),
+
+    #[property("editor-caret-color")]
+    #[parser("parse_color")]
+    #[style_class(CursorColor)]
+    EditorCaretColor(Color),
+
+    #[property("editor-selection-color")]
+    #[parser("parse_color")]
+    #[style_class(SelectionColor)]
+    EditorSelectionColor(Color),
+
+    #[property("editor-current-line-color")]
+    #[parser("parse_opt_color")]
+    #[style_class(CurrentLineColor)]
+    EditorCurrentLineColor(Option<Color>),
+
+    #[property("editor-gutter-color")]
+    #[parser("parse_opt_color")]
+    #[style_class(DimColor)]
+    EditorGutterColor(Option<Color>),
+
+    #[property("editor-gutter-padding-left")]
+    #[parser("parse_px")]
+    #[style_class(LeftOfCenterPadding)]
+    EditorGutterPaddingLeft(Px),
+
+    #[property("editor-gutter-padding-right")]
+    #[parser("parse_px")]
+    #[style_class(RightOfCenterPadding)]
+    EditorGutterPaddingRight(Px),
+
+    #[property("editor-indent-guide")]
+    #[parser("parse_bool")]
+    #[style_class(ShowIndentGuide)]
+    EditorIndentGuide(bool),
+
+    #[property("editor-indent-guide-color")]
+    #[parser("parse_color")]
+    #[style_class(IndentGuideColor)]
+    EditorIndentGuideColor(Color),
+
+    #[property("editor-whitespace-color")]
+    #[parser("parse_color")]
+    #[style_class(VisibleWhitespaceColor)]
+    EditorWhitespaceColor(Color),
+
+    #[property("editor-scroll-beyond-last-line")]
+    #[parser("parse_bool")]
+    #[style_class(ScrollBeyondLastLine)]
+    EditorScrollBeyondLastLine(bool),
+}
+
+/// Properties that Floem inherits from the parent element
+const INHERITED_PROPERTIES: &[&str] = &[
+    "color",
+    "font",
+    "font-size",
+    "font-family",
+    "font-weight",
+    "font-style",
+    "line-height",
+];
+
+/// Css-wide keyword that is a valid value of every property
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CssWideKeyword {
+    Inherit,
+    Initial,
+    Unset,
+    Revert,
+}
+
+impl CssWideKeyword {
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim() {
+            "inherit" => Some(Self::Inherit),
+            "initial" => Some(Self::Initial),
+            "unset" => Some(Self::Unset),
+            "revert" | "revert-layer" => Some(Self::Revert),
+            _ => None,
+        }
+    }
+
+    /// Floem resolves unset values from the parent for inherited props and from
+    /// the default for others, like css `unset`. There is no user agent stylesheet,
+    /// so `revert` is the same as `unset`.
+    fn style_value<P: StyleProp>(self, _prop: P) -> StyleValue<P::Type> {
+        match self {
+            Self::Initial => StyleValue::Val(P::default_value()),
+            Self::Inherit | Self::Unset | Self::Revert => StyleValue::Unset,
+        }
+    }
+}
+
+impl Declaration {
+    /// Sets every Floem prop of `property` by css-wide keyword, `all` sets every property.
+    /// Only properties Floem inherits can be `inherit`.
+    #[must_use]
+    pub fn apply_keyword(s: Style, property: &str, keyword: CssWideKeyword) -> Style {
+        if property == "all" {
+            return Self::PROPERTIES
+                .iter()
+                .filter(|p| keyword != CssWideKeyword::Inherit || INHERITED_PROPERTIES.contains(p))
+                .fold(s, |s, p| Self::apply_style_value(s, p, keyword));
+        }
+        if keyword == CssWideKeyword::Inherit && !INHERITED_PROPERTIES.contains(&property) {
+            log::warn!("Floem doesn't inherit '{property}', 'inherit' is ignored");
+            return s;
+        }
+        Self::apply_style_value(s, property, keyword)
+    }
+}
+
+/// Properties that are valid css but can't be rendered by Floem
+const UNSUPPORTED_PROPERTIES: &[&str] = &[
+    "grid-auto-flow",
+    "justify-items",
+    "transform-origin",
+    "border-top-left-radius",
+    "border-top-right-radius",
+    "border-bottom-right-radius",
+    "border-bottom-left-radius",
+    "border-left-color",
+    "border-top-color",
+    "border-right-color",
+    "border-bottom-color",
+    "opacity",
+    "visibility",
+    "overflow",
+    "overflow-x",
+    "overflow-y",
+    "letter-spacing",
+    "text-align",
+    "stroke",
+    "stroke-width",
+    "editor-caret-width",
+];
+
+/// Returns `true` if `property` is valid css but not supported by Floem
+#[must_use]
+pub fn is_unsupported(property: &str) -> bool {
+    UNSUPPORTED_PROPERTIES.contains(&property)
+}
+
+/// What to use instead of unsupported `property` that has a supported alternative
+#[must_use]
+pub fn unsupported_hint(property: &str) -> Option<&'static str> {
+    let side = property.strip_prefix("border-")?;
+    if side.ends_with("-radius") {
+        Some("Floem rounds all corners with one radius, use border-radius")
+    } else if side.ends_with("-color") {
+        Some("Floem paints all border sides with one color, use border-color")
+    } else {
+        None
+    }
 }
 
 impl Declaration {
@@ -350,38 +950,88 @@ impl Declaration {
             Self::MinHeight(v) => s.min_height(v),
             Self::MaxWidth(v) => s.max_width(v),
             Self::MaxHeight(v) => s.max_height(v),
-            Self::FlexDirection(f) => s.flex_direction(f),
+            Self::FlexDirection(f) => s.flex_direction(logical::flex_direction(f)),
             Self::FlexWrap(f) => s.flex_wrap(f),
             Self::FlexGrow(f) => s.flex_grow(f),
             Self::FlexShrink(f) => s.flex_shrink(f),
             Self::FlexBasis(v) => s.flex_basis(v),
+            Self::Flex(v) => s
+                .flex_grow(v.grow)
+                .flex_shrink(v.shrink)
+                .flex_basis(v.basis),
+            Self::FlexFlow((direction, wrap)) => s
+                .flex_direction(logical::flex_direction(direction))
+                .flex_wrap(wrap),
             Self::JustifyContent(j) => s.justify_content(j),
             Self::JustifySelf(a) => s.justify_self(a),
             Self::AlignItems(a) => s.align_items(a),
             Self::AlignContent(v) => s.align_content(v),
             Self::AlignSelf(v) => s.align_self(v),
-            Self::Border(b) => s
-                .apply_opt(b.width, |s, v| s.border(v.0))
-                .apply_opt(b.color, Style::border_color),
-            Self::BorderWidth(v) => s.border(v.0),
-            Self::BorderLeft(v) => s.border_left(v.0),
-            Self::BorderTop(v) => s.border_top(v.0),
-            Self::BorderRight(v) => s.border_right(v.0),
-            Self::BorderBottom(v) => s.border_bottom(v.0),
+            Self::PlaceContent((a, j)) => s.align_content(a).justify_content(j),
+            Self::PlaceItems(v) => s.align_items(v),
+            Self::PlaceSelf((a, j)) => s.align_self(a).justify_self(j),
+            Self::GridTemplateColumns(v) => s.grid_template_columns(v),
+            Self::GridTemplateRows(v) => s.grid_template_rows(v),
+            Self::GridAutoColumns(v) => s.grid_auto_columns(v),
+            Self::GridAutoRows(v) => s.grid_auto_rows(v),
+            Self::GridColumn(v) => s.grid_column(v),
+            Self::GridRow(v) => s.grid_row(v),
+            Self::Border(b) => {
+                let s = apply_border_side(s, BorderTop, &b);
+                let s = apply_border_side(s, BorderRight, &b);
+                let s = apply_border_side(s, BorderBottom, &b);
+                apply_border_side(s, BorderLeft, &b).apply_opt(b.color, Style::border_color)
+            }
+            Self::BorderWidth(v) => s
+                .border_top(v.top.0)
+                .border_right(v.right.0)
+                .border_bottom(v.bottom.0)
+                .border_left(v.left.0),
+            Self::BorderStyle(v) => {
+                let s = apply_border_style(s, BorderTop, v.top);
+                let s = apply_border_style(s, BorderRight, v.right);
+                let s = apply_border_style(s, BorderBottom, v.bottom);
+                apply_border_style(s, BorderLeft, v.left)
+            }
+            Self::BorderLeft(b) => apply_border_side(s, BorderLeft, &b),
+            Self::BorderTop(b) => apply_border_side(s, BorderTop, &b),
+            Self::BorderRight(b) => apply_border_side(s, BorderRight, &b),
+            Self::BorderBottom(b) => apply_border_side(s, BorderBottom, &b),
+            Self::BorderLeftWidth(v) => s.border_left(v.0),
+            Self::BorderTopWidth(v) => s.border_top(v.0),
+            Self::BorderRightWidth(v) => s.border_right(v.0),
+            Self::BorderBottomWidth(v) => s.border_bottom(v.0),
             Self::BorderRadius(v) => s.border_radius(v),
+            Self::Outline(b) => {
+                apply_border_side(s, Outline, &b).apply_opt(b.color, Style::outline_color)
+            }
+            Self::OutlineWidth(v) => s.outline(v.0),
+            Self::OutlineStyle(v) => apply_border_style(s, Outline, v),
             Self::OutlineColor(v) => s.outline_color(v),
-            Self::Outline(v) => s.outline(v.0),
             Self::BorderColor(v) => s.border_color(v),
-            Self::Padding(v) => s.padding(v),
+            Self::Padding(v) => s
+                .padding_top(v.top)
+                .padding_right(v.right)
+                .padding_bottom(v.bottom)
+                .padding_left(v.left),
             Self::PaddingLeft(v) => s.padding_left(v),
             Self::PaddingTop(v) => s.padding_top(v),
             Self::PaddingRight(v) => s.padding_right(v),
             Self::PaddingBottom(v) => s.padding_bottom(v),
-            Self::Margin(v) => s.margin(v),
+            Self::Margin(v) => s
+                .margin_top(v.top)
+                .margin_right(v.right)
+                .margin_bottom(v.bottom)
+                .margin_left(v.left),
             Self::MarginLeft(v) => s.margin_left(v),
             Self::MarginTop(v) => s.margin_top(v),
             Self::MarginRight(v) => s.margin_right(v),
             Self::MarginBottom(v) => s.margin_bottom(v),
+            Self::Inset(v) => s
+                .inset_top(v.top)
+                .inset_right(v.right)
+                .inset_bottom(v.bottom)
+                .inset_left(v.left),
             Self::InsetLeft(v) => s.inset_left(v),
             Self::InsetTop(v) => s.inset_top(v),
             Self::InsetRight(v) => s.inset_right(v),
@@ -389,7 +1039,10 @@ impl Declaration {
             Self::ZIndex(v) => s.z_index(v),
             Self::Cursor(v) => s.cursor(v),
             Self::Color(v) => s.color(v),
+            Self::Fill(v) => s.set(SvgColor, v),
             Self::BackgroundColor(v) => s.background(v),
+            Self::Background(v) => s.background(v),
+            Self::BackgroundImage(v) => s.apply_opt(v, Style::background),
             Self::BoxShadow(b) => s
                 .box_shadow_blur(b.blur_radius)
                 .box_shadow_color(b.color)
@@ -402,17 +1055,89 @@ impl Declaration {
             Self::FontStyle(v) => s.font_style(v),
             Self::CursorColor(v) => s.cursor_color(Brush::Solid(v)),
             Self::TextOverflow(v) => s.text_overflow(v),
-            Self::LineHeight(v) => s.line_height(v),
+            Self::LineHeight(v) => s.set(LineHeight, v),
+            Self::Font(v) => s
+                .font_style(v.style)
+                .font_weight(v.weight)
+                .font_size(v.size)
+                .set(LineHeight, v.line_height)
+                .font_family(v.family),
             Self::AspectRatio(v) => s.aspect_ratio(v),
             Self::ColGap(v) => s.column_gap(v),
             Self::RowGap(v) => s.row_gap(v),
             Self::Gap(v) => s.row_gap(v.0).apply_opt(v.1, Style::column_gap),
-            Self::Transition((key, t)) => Self::apply_transition(s, &key, t),
+            Self::Transform(v) => apply_rotate(
+                apply_scale(apply_translate(s, v.translate), v.scale),
+                v.rotate,
+            ),
+            Self::Translate(v) => apply_translate(s, v),
+            Self::Scale(v) => apply_scale(s, v),
+            Self::Rotate(v) => apply_rotate(s, v),
+            Self::Transition(v) => v.apply(s.set(TransitionProp, v.clone())),
+            Self::Animation(v) => s
+                .set(AnimationNameProp, v.name)
+                .set(AnimationDurationProp, v.duration)
+                .set(AnimationTimingFunctionProp, v.timing_function)
+                .set(AnimationDelayProp, v.delay)
+                .set(
+                    AnimationIterationCountProp,
+                    v.iteration_count.unwrap_or(IterationCount::Count(1)),
+                )
+                .set(AnimationDirectionProp, v.direction),
+            Self::AnimationName(v) => s.set(AnimationNameProp, v),
+            Self::AnimationDuration(v) => s.set(AnimationDurationProp, v),
+            Self::AnimationTimingFunction(v) => s.set(AnimationTimingFunctionProp, v),
+            Self::AnimationDelay(v) => s.set(AnimationDelayProp, v),
+            Self::AnimationIterationCount(v) => s.set(AnimationIterationCountProp, v),
+            Self::AnimationDirection(v) => s.set(AnimationDirectionProp, v),
+            Self::TransitionDelay(v) => {
+                let mut list = match s.get_style_value(TransitionProp) {
+                    StyleValue::Val(list) => list,
+                    _ => TransitionList::default(),
+                };
+                list.delays = Some(v);
+                list.apply(s.set(TransitionProp, list.clone()))
+            }
             Self::UserSelect(v) => s.selectable(v),
+            Self::EditorCaretColor(v) => s.cursor_color(Brush::Solid(v)),
+            Self::EditorSelectionColor(v) => s.set(SelectionColor, v),
+            Self::EditorCurrentLineColor(v) => s.set(CurrentLineColor, v),
+            Self::EditorGutterColor(v) => s.set(DimColor, v),
+            Self::EditorGutterPaddingLeft(v) => s.set(LeftOfCenterPadding, v.0),
+            Self::EditorGutterPaddingRight(v) => s.set(RightOfCenterPadding, v.0),
+            Self::EditorIndentGuide(v) => s.set(ShowIndentGuide, v),
+            Self::EditorIndentGuideColor(v) => s.set(IndentGuideColor, v),
+            Self::EditorWhitespaceColor(v) => s.set(VisibleWhitespaceColor, v),
+            Self::EditorScrollBeyondLastLine(v) => s.set(ScrollBeyondLastLine, v),
         }
     }
 }
 
+/// Sets width and style of border side or outline. Current width of the side
+/// is kept if only style is given.
+fn apply_border_side<P>(s: Style, prop: P, border: &BorderDef) -> Style
+where
+    P: StyleProp<Type = StrokeWrap>,
+{
+    if border.width.is_none() && border.style.is_none() {
+        return s;
+    }
+    let width = border.width.map_or_else(|| s.get(prop).0.width, |w| w.0);
+    let stroke = border.style.unwrap_or_default().stroke(width);
+    s.set(prop, stroke)
+}
+
+fn apply_border_style<P>(s: Style, prop: P, style: BorderStyle) -> Style
+where
+    P: StyleProp<Type = StrokeWrap>,
+{
+    let border = BorderDef {
+        style: Some(style),
+        ..BorderDef::default()
+    };
+    apply_border_side(s, prop, &border)
+}
+
 #[derive(Debug)]
 pub struct ParseError<'a> {
     pub error: &'static str,
@@ -505,7 +1230,7 @@ pub const fn parse_flex_direction(s: &str) -> Option<FlexDirection> {
 pub const fn parse_flex_wrap(s: &str) -> Option<FlexWrap> {
     match s.as_bytes() {
         b"wrap" => Some(FlexWrap::Wrap),
-        b"no-wrap" => Some(FlexWrap::NoWrap),
+        b"nowrap" | b"no-wrap" => Some(FlexWrap::NoWrap),
         b"wrap-reverse" => Some(FlexWrap::WrapReverse),
         _ => None,
     }
@@ -515,12 +1240,82 @@ fn parse_f32(s: &str) -> Option<f32> {
     s.parse::<f32>().ok()
 }
 
-fn parse_px(s: &str) -> Option<Px> {
-    let pixels = s.strip_suffix("px")?;
-    match pixels.trim_end().parse::<f64>() {
-        Ok(value) => Some(Px(value)),
-        Err(_) => None,
+/// `flex` shorthand: `none`, `auto`, `initial` or `<grow> <shrink>?` and `<basis>`
+/// in either order. Basis defaults to `0px` when grow is given, like in css.
+fn parse_flex(s: &str) -> Option<FlexDef> {
+    match s.trim() {
+        "none" => {
+            return Some(FlexDef {
+                grow: 0.0,
+                shrink: 0.0,
+                basis: PxPctAuto::Auto,
+            })
+        }
+        "auto" => {
+            return Some(FlexDef {
+                grow: 1.0,
+                shrink: 1.0,
+                basis: PxPctAuto::Auto,
+            })
+        }
+        "initial" => return Some(FlexDef::default()),
+        _ => (),
+    }
+    let mut factors = SmallVec::<[f32; 2]>::new();
+    let mut basis = None;
+    // Grow and shrink must be next to each other
+    let mut basis_between = false;
+    for part in s.split_whitespace() {
+        // Number after grow and shrink can only be unitless `0` basis
+        let factor = parse_f32(part).filter(|f| *f >= 0.0 && factors.len() < 2 && !basis_between);
+        if let Some(factor) = factor {
+            factors.push(factor);
+        } else if basis.is_none() {
+            basis = Some(parse_pxpctauto(part)?);
+            basis_between = !factors.is_empty();
+        } else {
+            return None;
+        }
+    }
+    if factors.is_empty() && basis.is_none() {
+        return None;
+    }
+    Some(FlexDef {
+        grow: factors.first().copied().unwrap_or(1.0),
+        shrink: factors.get(1).copied().unwrap_or(1.0),
+        basis: basis.unwrap_or(PxPctAuto::Px(0.0)),
+    })
+}
+
+/// `flex-flow` shorthand, `<direction>` and `<wrap>` in either order
+fn parse_flex_flow(s: &str) -> Option<(FlexDirection, FlexWrap)> {
+    let mut direction = None;
+    let mut wrap = None;
+    for part in s.split_whitespace() {
+        if let Some(d) = parse_flex_direction(part).filter(|_| direction.is_none()) {
+            direction = Some(d);
+        } else if let Some(w) = parse_flex_wrap(part).filter(|_| wrap.is_none()) {
+            wrap = Some(w);
+        } else {
+            return None;
+        }
+    }
+    if direction.is_none() && wrap.is_none() {
+        return None;
     }
+    Some((
+        direction.unwrap_or(FlexDirection::Row),
+        wrap.unwrap_or(FlexWrap::NoWrap),
+    ))
+}
+
+/// Length in px, relative units are resolved with current `Units`
+fn parse_px(s: &str) -> Option<Px> {
+    units::parse_length(s).map(Px)
+}
+
+fn parse_font_size(s: &str) -> Option<Px> {
+    units::parse_font_size(s).map(Px)
 }
 
 fn parse_pct(s: &str) -> Option<Pct> {
@@ -552,14 +1347,71 @@ fn parse_pxpctauto(s: &str) -> Option<PxPctAuto> {
     }
 }
 
-fn get_rgb_value(s: &str) -> Option<(usize, usize)> {
-    let start = s.find('(').unwrap_or(0);
-    let end = s[start..].find(')').unwrap_or(0);
-    if end > start {
-        Some((start + 1, end + 1))
-    } else {
-        None
+/// Expands 1-4 value shorthand in css order top, right, bottom, left
+fn parse_sides<T: Copy>(s: &str, parse: impl Fn(&str) -> Option<T>) -> Option<Sides<T>> {
+    let mut values = SmallVec::<[T; 4]>::new_const();
+    for part in split_outside_parens(s, char::is_whitespace) {
+        if values.len() == 4 {
+            return None;
+        }
+        values.push(parse(part)?);
+    }
+    let (top, right, bottom, left) = match values.as_slice() {
+        [all] => (*all, *all, *all, *all),
+        [vertical, horizontal] => (*vertical, *horizontal, *vertical, *horizontal),
+        [top, horizontal, bottom] => (*top, *horizontal, *bottom, *horizontal),
+        [top, right, bottom, left] => (*top, *right, *bottom, *left),
+        _ => return None,
+    };
+    Some(Sides {
+        top,
+        right,
+        bottom,
+        left,
+    })
+}
+
+/// Floem rounds all corners with same radius, so corners must be equal
+fn parse_border_radius(s: &str) -> Option<PxPct> {
+    if s.contains('/') {
+        log::warn!("Elliptical border-radius '{s}' is not supported by Floem");
+        return None;
+    }
+    let radii = split_outside_parens(s, char::is_whitespace)
+        .into_iter()
+        .map(parse_px_pct)
+        .collect::<Option<SmallVec<[_; 4]>>>()?;
+    let (first, rest) = radii.split_first()?;
+    if rest.len() > 3 {
+        return None;
+    }
+    if rest.iter().any(|r| r != first) {
+        log::warn!("Floem supports one radius for all corners, border-radius '{s}' is ignored");
+        return None;
     }
+    Some(*first)
+}
+
+fn parse_padding(s: &str) -> Option<Sides<PxPct>> {
+    parse_sides(s, parse_px_pct)
+}
+
+fn parse_margin(s: &str) -> Option<Sides<PxPctAuto>> {
+    parse_sides(s, parse_pxpctauto)
+}
+
+fn parse_inset(s: &str) -> Option<Sides<PxPctAuto>> {
+    parse_sides(s, parse_pxpctauto)
+}
+
+fn parse_border_width(s: &str) -> Option<Sides<Px>> {
+    parse_sides(s, parse_px)
+}
+
+fn get_rgb_value(s: &str) -> Option<(usize, usize)> {
+    let start = s.find('(')?;
+    let end = start + s[start..].find(')')?;
+    Some((start + 1, end))
 }
 
 fn parse_color(s: &str) -> Option<Color> {
@@ -574,8 +1426,12 @@ fn parse_color(s: &str) -> Option<Color> {
         let (start, end) = get_rgb_value(s)?;
         return parse_rgb(&s[start..end]);
     }
-    if s.starts_with("hsl") || s.starts_with("hwb") {
-        // TODO Support these maybe
+    if s.starts_with("hsl") {
+        let (start, end) = get_rgb_value(s)?;
+        return parse_hsl(&s[start..end]);
+    }
+    if s.starts_with("hwb") {
+        // TODO Support this maybe
         return None;
     }
     Color::parse(s)
@@ -606,25 +1462,150 @@ pub const fn parse_cursor_style(s: &str) -> Option<CursorStyle> {
     }
 }
 
-fn to_owned(s: &str) -> Option<String> {
-    Some(s.to_string())
+/// Weights from 1 to 1000 and keywords. Parent weight is not known when styles
+/// are parsed, so `lighter` and `bolder` are relative to `normal`.
+#[must_use]
+pub fn parse_font_weight(s: &str) -> Option<Weight> {
+    match s {
+        "thin" | "lighter" => Some(Weight(100)),
+        "normal" => Some(Weight(400)),
+        "bold" | "bolder" => Some(Weight(700)),
+        _ => {
+            let weight = s.parse::<f32>().ok()?;
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            (1.0..=1000.0)
+                .contains(&weight)
+                .then(|| Weight(weight.round() as u16))
+        }
+    }
 }
 
-pub const fn parse_font_weight(s: &str) -> Option<Weight> {
-    match s.as_bytes() {
-        b"100" | b"thin" => Some(Weight(100)),
-        b"200" => Some(Weight(200)),
-        b"300" => Some(Weight(300)),
-        b"400" | b"normal" => Some(Weight(400)),
-        b"500" => Some(Weight(500)),
-        b"600" => Some(Weight(600)),
-        b"700" | b"bold" => Some(Weight(700)),
-        b"800" => Some(Weight(800)),
-        b"900" => Some(Weight(900)),
+/// Comma separated font stack of quoted or unquoted names and generic families.
+/// Names are quoted in the result so that Floem doesn't mistake them for generic families.
+fn parse_font_family(s: &str) -> Option<String> {
+    let mut families = SmallVec::<[String; 4]>::new();
+    let mut rest = s.trim();
+    while !rest.is_empty() {
+        if let Some(quote) = rest.chars().next().filter(|c| matches!(c, '"' | '\'')) {
+            let end = rest[1..].find(quote)? + 1;
+            let name = rest[1..end].trim();
+            if name.is_empty() {
+                return None;
+            }
+            families.push(format!("\"{name}\""));
+            rest = rest[end + 1..].trim_start();
+        } else {
+            let end = rest.find(',').unwrap_or(rest.len());
+            let name = rest[..end].split_whitespace().collect::<SmallVec<[_; 4]>>();
+            if name.is_empty() || name.iter().any(|n| n.contains(['"', '\''])) {
+                return None;
+            }
+            let name = name.join(" ");
+            families
+                .push(generic_family(&name).map_or_else(|| format!("\"{name}\""), str::to_string));
+            rest = &rest[end..];
+        }
+        if let Some(r) = rest.strip_prefix(',') {
+            rest = r.trim_start();
+            if rest.is_empty() {
+                return None;
+            }
+        } else if !rest.is_empty() {
+            return None;
+        }
+    }
+    (!families.is_empty()).then(|| families.join(", "))
+}
+
+/// Generic families known by Floem, `system-ui` and `ui-*` families map to the closest one
+fn generic_family(name: &str) -> Option<&'static str> {
+    match name.to_ascii_lowercase().as_str() {
+        "serif" | "ui-serif" => Some("serif"),
+        "sans-serif" | "system-ui" | "ui-sans-serif" | "ui-rounded" => Some("sans-serif"),
+        "monospace" | "ui-monospace" => Some("monospace"),
+        "cursive" => Some("cursive"),
+        "fantasy" => Some("fantasy"),
         _ => None,
     }
 }
 
+/// Multiplier, length or percentage of font size. `normal` is Floem's default.
+fn parse_line_height(s: &str) -> Option<Option<LineHeightValue>> {
+    if s == "normal" {
+        return Some(None);
+    }
+    #[allow(clippy::cast_possible_truncation)]
+    if let Some(pct) = parse_pct(s) {
+        return Some(Some(LineHeightValue::Normal((pct.0 / 100.0) as f32)));
+    }
+    if let Some(multiplier) = parse_f32(s).filter(|v| *v >= 0.0) {
+        return Some(Some(LineHeightValue::Normal(multiplier)));
+    }
+    #[allow(clippy::cast_possible_truncation)]
+    units::parse_text_length(s).map(|px| Some(LineHeightValue::Px(px as f32)))
+}
+
+/// `font` shorthand: `[style] [weight] <size>[/<line-height>] <family>`.
+/// Variant and stretch keywords are ignored, system fonts are not supported.
+fn parse_font(s: &str) -> Option<FontDef> {
+    // Allow spaces around the line height separator
+    let s = s
+        .split('/')
+        .map(str::trim)
+        .collect::<SmallVec<[_; 2]>>()
+        .join("/");
+    let mut style = None;
+    let mut weight = None;
+    let mut rest = s.trim();
+    let (size, line_height) = loop {
+        let (part, r) = rest.split_once(char::is_whitespace)?;
+        rest = r.trim_start();
+        let (size, line_height) = part
+            .split_once('/')
+            .map_or((part, None), |(s, l)| (s, Some(l)));
+        if let Some(size) = parse_font_size(size) {
+            let line_height = match line_height {
+                Some(l) => parse_line_height(l)?,
+                None => None,
+            };
+            break (size, line_height);
+        }
+        if line_height.is_some() {
+            return None;
+        }
+        if part == "normal" {
+            continue;
+        }
+        if let Some(v) = parse_font_style(part).filter(|_| style.is_none()) {
+            style = Some(v);
+        } else if let Some(v) = parse_font_weight(part).filter(|_| weight.is_none()) {
+            weight = Some(v);
+        } else if matches!(
+            part,
+            "small-caps"
+                | "ultra-condensed"
+                | "extra-condensed"
+                | "condensed"
+                | "semi-condensed"
+                | "semi-expanded"
+                | "expanded"
+                | "extra-expanded"
+                | "ultra-expanded"
+        ) {
+            log::warn!("Font keyword '{part}' is not supported by Floem");
+        } else {
+            return None;
+        }
+    };
+    Some(FontDef {
+        style: style.unwrap_or(floem::text::Style::Normal),
+        weight: weight.unwrap_or(Weight::NORMAL),
+        size,
+        line_height,
+        family: parse_font_family(rest)?,
+    })
+}
+
 pub const fn parse_font_style(s: &str) -> Option<floem::text::Style> {
     match s.as_bytes() {
         b"normal" => Some(floem::text::Style::Normal),
@@ -651,31 +1632,285 @@ pub fn parse_gap(s: &str) -> Option<(PxPct, Option<PxPct>)> {
     let col_px_pct = parse_px_pct(col_val);
     Some((row_px_pct, col_px_pct))
 }
-#[allow(clippy::many_single_char_names)]
-fn parse_box_shadow(s: &str) -> Option<BoxShadow> {
-    let mut parts = SmallVec::<[&str; 5]>::new_const();
-    let mut start = 0;
-    let mut after_wp = false;
-    for (i, c) in s.char_indices() {
-        if c.is_whitespace() {
-            parts.push(&s[start..i]);
-            after_wp = true;
-            start = i + 1;
-        } else if after_wp && c.is_alphabetic() {
-            break;
+/// `background` shorthand, color or image of the first layer.
+/// Position, size and repeat parts are not supported.
+fn parse_background(s: &str) -> Option<Brush> {
+    if s.trim() == "none" {
+        return Some(Brush::Solid(Color::TRANSPARENT));
+    }
+    let layers = split_outside_parens(s, |c| c == ',');
+    let (first, dropped) = layers.split_first()?;
+    if !dropped.is_empty() {
+        log::warn!("Floem renders only one background layer, using '{first}'");
+    }
+    let mut brush = None;
+    for part in split_outside_parens(first, char::is_whitespace) {
+        if part == "none" {
+            // No image, color is painted
+            continue;
+        }
+        if let Some(color) = parse_color(part) {
+            brush.get_or_insert(Brush::Solid(color));
         } else {
-            after_wp = false;
+            // Image is painted over the color, Floem can't paint both
+            brush = Some(parse_background_image(part)??);
+        }
+    }
+    brush
+}
+
+/// Brush of svg icons, Floem paints the whole svg with it.
+/// `currentColor` follows the text color like Floem's default.
+fn parse_fill(s: &str) -> Option<Option<Brush>> {
+    match s.trim() {
+        "currentColor" | "currentcolor" => Some(None),
+        "none" => Some(Some(Brush::Solid(Color::TRANSPARENT))),
+        s => parse_color(s)
+            .map(Brush::Solid)
+            .or_else(|| parse_background_image(s).flatten())
+            .map(Some),
+    }
+}
+
+/// Floem has one background brush, so `none` keeps the current background
+fn parse_background_image(s: &str) -> Option<Option<Brush>> {
+    let s = s.trim();
+    if s == "none" {
+        return Some(None);
+    }
+    if let Some(args) = function_args(s, "linear-gradient") {
+        return parse_linear_gradient(args).map(|g| Some(Brush::Gradient(g)));
+    }
+    if let Some(args) = function_args(s, "radial-gradient") {
+        return parse_radial_gradient(args).map(|g| Some(Brush::Gradient(g)));
+    }
+    if s.starts_with("url(") {
+        log::warn!("Background image '{s}' is not supported by Floem, use a gradient or color");
+    }
+    None
+}
+
+/// Length of gradient in px when no stop has px position and radial gradient
+/// has no radius, Floem doesn't tell the view size to gradients
+const DEFAULT_GRADIENT_LENGTH: f64 = 100.0;
+
+/// Floem paints gradients in view coordinates without knowing the view size,
+/// so gradient line length is taken from the largest px stop position.
+/// Percentages are relative to that length.
+fn parse_linear_gradient(args: &str) -> Option<Gradient> {
+    let parts = split_outside_parens(args, |c| c == ',');
+    let (first, rest) = parts.split_first()?;
+    let (angle, stops) = match parse_gradient_direction(first) {
+        Some(angle) => (angle, rest),
+        None => (180.0, parts.as_slice()),
+    };
+    let (length, stops) = parse_color_stops(stops, None)?;
+    let (sin, cos) = angle.to_radians().sin_cos();
+    let half = length / 2.0;
+    let start = Point::new(half - sin * half, half + cos * half);
+    let end = Point::new(half + sin * half, half - cos * half);
+    Some(Gradient::new_linear(start, end).with_stops(stops.as_slice()))
+}
+
+/// Supports `circle`, px radius and px center with `at`. Without center the
+/// gradient is centered in a box twice the radius.
+fn parse_radial_gradient(args: &str) -> Option<Gradient> {
+    let parts = split_outside_parens(args, |c| c == ',');
+    let (first, rest) = parts.split_first()?;
+    let (shape, stops) = if parse_color_stop(first).is_some() {
+        (None, parts.as_slice())
+    } else {
+        (Some(parse_radial_shape(first)?), rest)
+    };
+    let (radius, center) = shape.unwrap_or((None, None));
+    let (radius, stops) = parse_color_stops(stops, radius)?;
+    let center = center.unwrap_or(Point::new(radius, radius));
+    #[allow(clippy::cast_possible_truncation)]
+    Some(Gradient::new_radial(center, radius as f32).with_stops(stops.as_slice()))
+}
+
+fn parse_radial_shape(s: &str) -> Option<(Option<f64>, Option<Point>)> {
+    let (shape, at) = match s.split_once("at ") {
+        Some((shape, at)) => (shape, Some(at)),
+        None => (s, None),
+    };
+    let mut radius = None;
+    for part in shape.split_whitespace() {
+        match part {
+            "circle" => (),
+            "ellipse" => log::warn!("Elliptical radial-gradient is painted as circle"),
+            px => radius = Some(parse_px(px)?.0),
         }
     }
-    parts.push(&s[start..]);
+    let center = match at.map(|at| at.split_whitespace().collect::<SmallVec<[_; 2]>>()) {
+        Some(at) => match at.as_slice() {
+            [x, y] => Some(Point::new(parse_px(x)?.0, parse_px(y)?.0)),
+            _ => return None,
+        },
+        None => None,
+    };
+    Some((radius, center))
+}
+
+/// Angle in degrees, clockwise from top like in css
+fn parse_gradient_direction(s: &str) -> Option<f64> {
+    let Some(side) = s.strip_prefix("to ") else {
+        return parse_angle(s);
+    };
+    let mut parts = side.split_whitespace().collect::<SmallVec<[_; 2]>>();
+    parts.sort_unstable();
     match parts.as_slice() {
-        ["none"] => Some(BoxShadow::default()),
+        ["top"] => Some(0.0),
+        ["right", "top"] => Some(45.0),
+        ["right"] => Some(90.0),
+        ["bottom", "right"] => Some(135.0),
+        ["bottom"] => Some(180.0),
+        ["bottom", "left"] => Some(225.0),
+        ["left"] => Some(270.0),
+        ["left", "top"] => Some(315.0),
+        _ => None,
+    }
+}
+
+fn parse_angle(s: &str) -> Option<f64> {
+    if let Some(deg) = s.strip_suffix("deg") {
+        return deg.parse().ok();
+    }
+    if let Some(grad) = s.strip_suffix("grad") {
+        return grad.parse::<f64>().ok().map(|v| v * 0.9);
+    }
+    if let Some(rad) = s.strip_suffix("rad") {
+        return rad.parse::<f64>().ok().map(f64::to_degrees);
+    }
+    if let Some(turn) = s.strip_suffix("turn") {
+        return turn.parse::<f64>().ok().map(|v| v * 360.0);
+    }
+    None
+}
+
+/// Color and position of one stop, or two stops if it has two positions
+fn parse_color_stop(s: &str) -> Option<(Color, SmallVec<[PxPct; 2]>)> {
+    let parts = split_outside_parens(s, char::is_whitespace);
+    let (color, positions) = parts.split_first()?;
+    let color = parse_color(color)?;
+    let positions = positions
+        .iter()
+        .map(|p| parse_px_pct(p))
+        .collect::<Option<SmallVec<_>>>()?;
+    (positions.len() <= 2).then_some((color, positions))
+}
+
+/// Resolves stops to normalized offsets. Length is `length`, the largest
+/// px position or `DEFAULT_GRADIENT_LENGTH`.
+fn parse_color_stops(
+    parts: &[&str],
+    length: Option<f64>,
+) -> Option<(f64, SmallVec<[ColorStop; 4]>)> {
+    let mut stops = SmallVec::<[(Color, Option<PxPct>); 4]>::new();
+    for part in parts {
+        let (color, positions) = parse_color_stop(part)?;
+        if positions.is_empty() {
+            stops.push((color, None));
+        }
+        stops.extend(positions.into_iter().map(|p| (color, Some(p))));
+    }
+    if stops.len() < 2 {
+        return None;
+    }
+    let largest_px = stops
+        .iter()
+        .filter_map(|(_, p)| match p {
+            Some(PxPct::Px(px)) => Some(*px),
+            _ => None,
+        })
+        .reduce(f64::max);
+    let length = length
+        .or(largest_px)
+        .filter(|l| *l > 0.0)
+        .unwrap_or(DEFAULT_GRADIENT_LENGTH);
+    let mut offsets = stops
+        .iter()
+        .map(|(_, p)| match p {
+            Some(PxPct::Px(px)) => Some(px / length),
+            Some(PxPct::Pct(pct)) => Some(pct / 100.0),
+            None => None,
+        })
+        .collect::<SmallVec<[_; 4]>>();
+    let last = offsets.len() - 1;
+    offsets[0].get_or_insert(0.0);
+    offsets[last].get_or_insert(1.0);
+    // Stops without position are spread evenly between positioned ones
+    let mut prev = 0;
+    for i in 1..=last {
+        let Some(next) = offsets[i] else { continue };
+        let from = offsets[prev].unwrap_or(0.0);
+        #[allow(clippy::cast_precision_loss)]
+        let step = (next - from) / (i - prev) as f64;
+        for (n, offset) in offsets[prev + 1..i].iter_mut().enumerate() {
+            #[allow(clippy::cast_precision_loss)]
+            let n = (n + 1) as f64;
+            *offset = Some(from + step * n);
+        }
+        prev = i;
+    }
+    let mut max = 0.0_f64;
+    let stops = stops
+        .iter()
+        .zip(offsets)
+        .map(|((color, _), offset)| {
+            max = max.max(offset.unwrap_or(max));
+            #[allow(clippy::cast_possible_truncation)]
+            let offset = max as f32;
+            ColorStop {
+                offset,
+                color: *color,
+            }
+        })
+        .collect();
+    Some((length, stops))
+}
+
+/// Floem renders single outer shadow, so the first outer layer of comma separated
+/// list is used. Inset layers and the rest of the layers are dropped with a warning.
+fn parse_box_shadow(s: &str) -> Option<BoxShadow> {
+    if s.trim() == "none" {
+        return Some(BoxShadow::default());
+    }
+    let mut rendered = None;
+    for layer in split_outside_parens(s, |c| c == ',') {
+        let (shadow, inset) = parse_box_shadow_layer(layer)?;
+        if inset {
+            log::warn!("Inset box-shadow '{layer}' is not supported by Floem");
+        } else if rendered.is_none() {
+            rendered = Some(shadow);
+        } else {
+            log::warn!("Floem renders only one box-shadow, dropping layer '{layer}'");
+        }
+    }
+    Some(rendered.unwrap_or_default())
+}
+
+/// Returns shadow and whether it has `inset` keyword
+#[allow(clippy::many_single_char_names)]
+fn parse_box_shadow_layer(s: &str) -> Option<(BoxShadow, bool)> {
+    let mut parts = split_outside_parens(s, char::is_whitespace);
+    let inset = if parts.first() == Some(&"inset") {
+        parts.remove(0);
+        true
+    } else if parts.last() == Some(&"inset") {
+        parts.pop();
+        true
+    } else {
+        false
+    };
+    let shadow = match parts.as_slice() {
         [a, b] => parse_box_shadow_2([a, b]),
         [a, b, c] => parse_box_shadow_3([a, b, c]),
         [a, b, c, d] => parse_box_shadow_4([a, b, c, d]),
         [a, b, c, d, e] => parse_box_shadow_5([a, b, c, d, e]),
         _ => None,
-    }
+    }?;
+    Some((shadow, inset))
 }
 
 fn parse_box_shadow_2([a, b]: [&str; 2]) -> Option<BoxShadow> {
@@ -842,6 +2077,40 @@ fn parse_rgb(s: &str) -> Option<Color> {
     None
 }
 
+/// `<hue>, <saturation>, <lightness>` with optional alpha, hue is degrees or angle
+fn parse_hsl(s: &str) -> Option<Color> {
+    let mut parts = SmallVec::<[&str; 4]>::new_const();
+    parts.extend(s.split(',').map(str::trim));
+    let (h, sat, l, a) = match parts.as_slice() {
+        [h, s, l] => (*h, *s, *l, 255),
+        [h, s, l, a] => (*h, *s, *l, parse_rgb_alpha(a)?),
+        _ => return None,
+    };
+    let h = h
+        .parse::<f64>()
+        .ok()
+        .or_else(|| parse_angle(h))?
+        .rem_euclid(360.0)
+        / 60.0;
+    let sat = parse_pct(sat)?.0.clamp(0.0, 100.0) / 100.0;
+    let l = parse_pct(l)?.0.clamp(0.0, 100.0) / 100.0;
+    let c = (1.0 - (2.0 * l - 1.0).abs()) * sat;
+    let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    let (r, g, b) = match h as u8 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let m = l - c / 2.0;
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    let channel = |v: f64| ((v + m) * 255.0).round() as u8;
+    Some(Color::rgba8(channel(r), channel(g), channel(b), a))
+}
+
 fn parse_rgb_value(s: &str) -> Option<u8> {
     s.parse::<u8>().ok()
 }
@@ -853,13 +2122,327 @@ fn parse_rgb_alpha(s: &str) -> Option<u8> {
         .ok()
 }
 
-fn parse_transition(s: &str) -> Option<(String, Transition)> {
-    let mut parts = s.split_whitespace();
-    let key = parts.next()?;
-    let duration_str = parts.next()?;
-    let duration = parse_duration(duration_str)?;
-    let transition = Transition::linear(duration);
-    Some((key.to_string(), transition))
+/// Comma separated list of `<property> <duration> <easing> <delay>`, where
+/// property, easing and delay are optional. Empty with reduced motion.
+fn parse_transition(s: &str) -> Option<TransitionList> {
+    if s.trim() == "none" {
+        return Some(TransitionList::default());
+    }
+    let mut transitions = split_outside_parens(s, |c| c == ',')
+        .into_iter()
+        .map(parse_transition_def)
+        .collect::<Option<SmallVec<[TransitionDef; 2]>>>()?;
+    if media::current().reduced_motion {
+        return Some(TransitionList::default());
+    }
+    transitions.retain(|t| {
+        let unsupported = is_unsupported(&t.property);
+        if unsupported {
+            log::warn!("Transition of '{}' is not supported by Floem", t.property);
+        }
+        !unsupported
+    });
+    Some(TransitionList {
+        transitions,
+        delays: None,
+    })
+}
+
+fn parse_transition_def(s: &str) -> Option<TransitionDef> {
+    let mut property = None;
+    let mut easing = None;
+    let mut times = SmallVec::<[Duration; 2]>::new();
+    for part in split_outside_parens(s, char::is_whitespace) {
+        if let Some(time) = parse_duration(part) {
+            times.push(time);
+        } else if let Some(e) = parse_easing(part).filter(|_| easing.is_none()) {
+            easing = Some(e);
+        } else if property.is_none() && times.is_empty() && easing.is_none() {
+            property = Some(part);
+        } else {
+            return None;
+        }
+    }
+    let property = property.unwrap_or("all");
+    let property = logical::physical_property(property).unwrap_or_else(|| property.to_string());
+    if property != "all"
+        && !Declaration::PROPERTIES.contains(&property.as_str())
+        && !is_unsupported(&property)
+    {
+        return None;
+    }
+    let (duration, delay) = match times.as_slice() {
+        [duration] => (*duration, Duration::ZERO),
+        [duration, delay] => (*duration, *delay),
+        _ => return None,
+    };
+    Some(TransitionDef {
+        property,
+        duration,
+        easing: easing.unwrap_or_else(|| Rc::new(Bezier::ease())),
+        delay,
+    })
+}
+
+fn parse_transition_delay(s: &str) -> Option<SmallVec<[Duration; 2]>> {
+    split_outside_parens(s, |c| c == ',')
+        .into_iter()
+        .map(parse_duration)
+        .collect()
+}
+
+/// Css timing functions and `spring` / `spring(<mass>, <stiffness>, <damping>, <velocity>?)`
+fn parse_easing(s: &str) -> Option<Rc<dyn Easing>> {
+    match s {
+        "linear" => return Some(Rc::new(Linear)),
+        "ease" => return Some(Rc::new(Bezier::ease())),
+        "ease-in" => return Some(Rc::new(Bezier::ease_in())),
+        "ease-out" => return Some(Rc::new(Bezier::ease_out())),
+        "ease-in-out" => return Some(Rc::new(Bezier::ease_in_out())),
+        // Floem's step positions are named after where the step is held
+        "step-start" => return Some(Rc::new(Step::END)),
+        "step-end" => return Some(Rc::new(Step::START)),
+        "spring" => return Some(Rc::new(Spring::default())),
+        _ => (),
+    }
+    if let Some(args) = function_args(s, "cubic-bezier") {
+        let args = parse_f64_args(args)?;
+        let [x1, y1, x2, y2] = args.as_slice() else {
+            return None;
+        };
+        let in_range = |x: &f64| (0.0..=1.0).contains(x);
+        return (in_range(x1) && in_range(x2)).then(|| Rc::new(Bezier(*x1, *y1, *x2, *y2)) as _);
+    }
+    if let Some(args) = function_args(s, "steps") {
+        let (steps, position) = match args.split_once(',') {
+            Some((steps, position)) => (steps.trim(), position.trim()),
+            None => (args.trim(), "end"),
+        };
+        let steps = steps.parse::<usize>().ok().filter(|n| *n > 0)?;
+        let position = match position {
+            "jump-start" | "start" => StepPosition::End,
+            "jump-end" | "end" => StepPosition::Start,
+            _ => {
+                log::warn!("Step position '{position}' is not supported by Floem");
+                return None;
+            }
+        };
+        return Some(Rc::new(Step::new(steps, position)));
+    }
+    if let Some(args) = function_args(s, "spring") {
+        let args = parse_f64_args(args)?;
+        let (mass, stiffness, damping, velocity) = match args.as_slice() {
+            [mass, stiffness, damping] => (*mass, *stiffness, *damping, 0.0),
+            [mass, stiffness, damping, velocity] => (*mass, *stiffness, *damping, *velocity),
+            _ => return None,
+        };
+        return Some(Rc::new(Spring::new(mass, stiffness, damping, velocity)));
+    }
+    None
+}
+
+fn apply_translate(s: Style, (x, y): (f64, f64)) -> Style {
+    s.translate_x(x).translate_y(y)
+}
+
+fn apply_scale(s: Style, (x, y): (f64, f64)) -> Style {
+    s.scale_x(Pct(x * 100.0)).scale_y(Pct(y * 100.0))
+}
+
+fn apply_rotate(s: Style, degrees: f64) -> Style {
+    s.rotate(Px(degrees.to_radians()))
+}
+
+/// Space separated transform functions. Translations add up, scales multiply
+/// and rotations add up, since Floem has fixed transform order.
+/// Floem translates before scaling and rotating, so translations must come first
+fn parse_transform(s: &str) -> Option<TransformDef> {
+    let mut def = TransformDef::default();
+    if s.trim() == "none" {
+        return Some(def);
+    }
+    let mut scaled_or_rotated = false;
+    for function in split_outside_parens(s, char::is_whitespace) {
+        let (name, args) = function.split_once('(')?;
+        let args = args.strip_suffix(')')?;
+        let args = split_outside_parens(args, |c| c == ',');
+        if name.starts_with("translate") && scaled_or_rotated {
+            log::warn!(
+                "Floem translates before scale and rotate, '{function}' must come first in '{s}'"
+            );
+            return None;
+        }
+        scaled_or_rotated |= name.starts_with("scale") || name == "rotate";
+        match (name, args.as_slice()) {
+            ("translate", [x]) => def.translate.0 += parse_translate_px(x)?,
+            ("translate", [x, y]) => {
+                def.translate.0 += parse_translate_px(x)?;
+                def.translate.1 += parse_translate_px(y)?;
+            }
+            ("translateX", [x]) => def.translate.0 += parse_translate_px(x)?,
+            ("translateY", [y]) => def.translate.1 += parse_translate_px(y)?,
+            ("scale", [v]) => {
+                let v = parse_scale_factor(v)?;
+                def.scale = (def.scale.0 * v, def.scale.1 * v);
+            }
+            ("scale", [x, y]) => {
+                def.scale.0 *= parse_scale_factor(x)?;
+                def.scale.1 *= parse_scale_factor(y)?;
+            }
+            ("scaleX", [x]) => def.scale.0 *= parse_scale_factor(x)?,
+            ("scaleY", [y]) => def.scale.1 *= parse_scale_factor(y)?,
+            ("rotate", [angle]) => def.rotate += parse_angle(angle)?,
+            _ => {
+                log::warn!("Transform function '{function}' is not supported by Floem");
+                return None;
+            }
+        }
+    }
+    Some(def)
+}
+
+/// Floem resolves translate percentages wrong, so only px are accepted
+fn parse_translate_px(s: &str) -> Option<f64> {
+    if s.ends_with('%') {
+        log::warn!("Percentage translate '{s}' is not supported by Floem, use px");
+        return None;
+    }
+    parse_px(s).map(|px| px.0)
+}
+
+/// Number or percentage, `1.5` and `150%` are same
+fn parse_scale_factor(s: &str) -> Option<f64> {
+    parse_pct(s)
+        .map(|pct| pct.0 / 100.0)
+        .or_else(|| s.parse().ok())
+}
+
+fn parse_translate(s: &str) -> Option<(f64, f64)> {
+    let parts = s.split_whitespace().collect::<SmallVec<[_; 2]>>();
+    match parts.as_slice() {
+        ["none"] => Some((0.0, 0.0)),
+        [x] => Some((parse_translate_px(x)?, 0.0)),
+        [x, y] => Some((parse_translate_px(x)?, parse_translate_px(y)?)),
+        _ => None,
+    }
+}
+
+fn parse_scale(s: &str) -> Option<(f64, f64)> {
+    let parts = s.split_whitespace().collect::<SmallVec<[_; 2]>>();
+    match parts.as_slice() {
+        ["none"] => Some((1.0, 1.0)),
+        [v] => parse_scale_factor(v).map(|v| (v, v)),
+        [x, y] => Some((parse_scale_factor(x)?, parse_scale_factor(y)?)),
+        _ => None,
+    }
+}
+
+fn parse_rotate(s: &str) -> Option<f64> {
+    match s.trim() {
+        "none" => Some(0.0),
+        angle => parse_angle(angle),
+    }
+}
+
+fn parse_timing_function(s: &str) -> Option<TimingFunction> {
+    parse_easing(s).map(TimingFunction)
+}
+
+/// `<name> <duration> <timing-function> <delay> <iteration-count> <direction>`
+/// in any order, first time is duration and second delay.
+/// Floem view gets one animation, so only the first of comma separated animations is used.
+fn parse_animation(s: &str) -> Option<AnimationDef> {
+    let layers = split_outside_parens(s, |c| c == ',');
+    let (first, dropped) = layers.split_first()?;
+    if !dropped.is_empty() {
+        log::warn!("Only one animation per element is supported, using '{first}'");
+    }
+    let mut def = AnimationDef::default();
+    let mut timing_function = None;
+    let mut times = 0;
+    let mut direction = None;
+    for part in split_outside_parens(first, char::is_whitespace) {
+        if let Some(time) = parse_duration(part) {
+            match times {
+                0 => def.duration = time,
+                1 => def.delay = time,
+                _ => return None,
+            }
+            times += 1;
+        } else if let Some(t) = parse_timing_function(part).filter(|_| timing_function.is_none()) {
+            timing_function = Some(t);
+        } else if let Some(d) = parse_animation_direction(part).filter(|_| direction.is_none()) {
+            direction = Some(d);
+        } else if let Some(c) =
+            parse_iteration_count(part).filter(|_| def.iteration_count.is_none())
+        {
+            def.iteration_count = Some(c);
+        } else if matches!(
+            part,
+            "forwards" | "backwards" | "both" | "running" | "paused"
+        ) {
+            log::warn!("Animation keyword '{part}' is not supported by Floem");
+        } else if def.name.is_none() {
+            def.name = Some(parse_animation_name(part)?.unwrap_or_default());
+        } else {
+            return None;
+        }
+    }
+    def.name = def.name.filter(|n| !n.is_empty());
+    def.timing_function = timing_function.unwrap_or_default();
+    def.direction = direction.unwrap_or_default();
+    Some(def)
+}
+
+fn parse_animation_name(s: &str) -> Option<Option<String>> {
+    let s = s.trim();
+    if s == "none" {
+        return Some(None);
+    }
+    let is_ident = s
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+        && !s.starts_with(|c: char| c.is_ascii_digit());
+    // Reduced motion parses the name, but doesn't run the animation
+    is_ident.then(|| Some(s.to_string()).filter(|_| !media::current().reduced_motion))
+}
+
+fn parse_iteration_count(s: &str) -> Option<IterationCount> {
+    if s == "infinite" {
+        return Some(IterationCount::Infinite);
+    }
+    let count = s.parse::<f64>().ok().filter(|c| *c >= 0.0)?;
+    if count.fract() != 0.0 {
+        log::warn!("Floem repeats animations whole times, rounding '{s}' up");
+    }
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Some(IterationCount::Count(count.ceil() as usize))
+}
+
+const fn parse_animation_direction(s: &str) -> Option<AnimationDirection> {
+    match s.as_bytes() {
+        b"normal" => Some(AnimationDirection::Normal),
+        b"reverse" => Some(AnimationDirection::Reverse),
+        b"alternate" => Some(AnimationDirection::Alternate),
+        b"alternate-reverse" => Some(AnimationDirection::AlternateReverse),
+        _ => None,
+    }
+}
+
+/// Keyframe selector `from`, `to` or percentage as offset between 0 and 1
+#[must_use]
+pub fn parse_keyframe_offset(s: &str) -> Option<f64> {
+    match s {
+        "from" => Some(0.0),
+        "to" => Some(1.0),
+        _ => parse_pct(s)
+            .map(|pct| pct.0 / 100.0)
+            .filter(|v| (0.0..=1.0).contains(v)),
+    }
+}
+
+fn parse_f64_args(s: &str) -> Option<SmallVec<[f64; 4]>> {
+    s.split(',').map(|v| v.trim().parse().ok()).collect()
 }
 
 fn parse_duration(s: &str) -> Option<Duration> {
@@ -870,7 +2453,7 @@ fn parse_duration(s: &str) -> Option<Duration> {
     }
     if let Some(seconds) = s.strip_suffix('s') {
         if let Ok(f) = seconds.parse::<f64>() {
-            if f > 0. {
+            if f >= 0. {
                 let ms = (f * 1000.) as u64;
                 return Some(Duration::from_millis(ms));
             }
@@ -879,6 +2462,22 @@ fn parse_duration(s: &str) -> Option<Duration> {
     None
 }
 
+const fn parse_bool(s: &str) -> Option<bool> {
+    match s.as_bytes() {
+        b"true" => Some(true),
+        b"false" => Some(false),
+        _ => None,
+    }
+}
+
+/// Color or `none`
+fn parse_opt_color(s: &str) -> Option<Option<Color>> {
+    if s == "none" {
+        return Some(None);
+    }
+    parse_color(s).map(Some)
+}
+
 const fn parse_user_select(s: &str) -> Option<bool> {
     match s.as_bytes() {
         b"none" => Some(false),
@@ -887,35 +2486,277 @@ const fn parse_user_select(s: &str) -> Option<bool> {
     }
 }
 
+fn parse_border_style(s: &str) -> Option<BorderStyle> {
+    match s {
+        "none" | "hidden" => Some(BorderStyle::None),
+        "solid" => Some(BorderStyle::Solid),
+        "dashed" => Some(BorderStyle::Dashed),
+        "dotted" => Some(BorderStyle::Dotted),
+        "double" | "groove" | "ridge" | "inset" | "outset" => {
+            log::warn!("Border style '{s}' is not supported by Floem, using solid");
+            Some(BorderStyle::Solid)
+        }
+        _ => None,
+    }
+}
+
+fn parse_border_styles(s: &str) -> Option<Sides<BorderStyle>> {
+    parse_sides(s, parse_border_style)
+}
+
+/// Floem paints all border sides with same color
+fn parse_border_colors(s: &str) -> Option<Color> {
+    let colors = parse_sides(s, parse_color)?;
+    if colors.right != colors.top || colors.bottom != colors.top || colors.left != colors.top {
+        log::warn!("Floem supports one border color for all sides, using '{s}' top color");
+    }
+    Some(colors.top)
+}
+
+/// Parses `<width> <style> <color>` in any order, each at most once
 fn parse_border(s: &str) -> Option<BorderDef> {
+    let mut retval = BorderDef::default();
+    let mut count = 0;
+    for val in split_outside_parens(s, char::is_whitespace) {
+        count += 1;
+        if retval.width.is_none() {
+            if let Some(px) = parse_px(val) {
+                retval.width = Some(px);
+                continue;
+            }
+        }
+        if retval.style.is_none() {
+            if let Some(style) = parse_border_style(val) {
+                retval.style = Some(style);
+                continue;
+            }
+        }
+        if retval.color.is_none() {
+            if let Some(color) = parse_color(val) {
+                retval.color = Some(color);
+                continue;
+            }
+        }
+        return None;
+    }
+    (count > 0).then_some(retval)
+}
+
+/// Floem paints all border sides with same color, so color of one side is dropped
+fn parse_border_side(s: &str) -> Option<BorderDef> {
+    let border = parse_border(s)?;
+    if border.color.is_some() {
+        log::warn!("Per-side border color in '{s}' is not supported by Floem, use border-color");
+    }
+    Some(BorderDef {
+        color: None,
+        ..border
+    })
+}
+
+/// Splits `s` by `is_sep` but not inside parentheses. Empty parts are skipped.
+fn split_outside_parens(s: &str, is_sep: impl Fn(char) -> bool) -> SmallVec<[&str; 8]> {
+    let mut parts = SmallVec::new();
+    let mut depth = 0_usize;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            c if depth == 0 && is_sep(c) => {
+                let part = s[start..i].trim();
+                if !part.is_empty() {
+                    parts.push(part);
+                }
+                start = i + c.len_utf8();
+            }
+            _ => (),
+        }
+    }
+    let part = s[start..].trim();
+    if !part.is_empty() {
+        parts.push(part);
+    }
+    parts
+}
+
+/// Returns arguments of css function `name(args)`
+fn function_args<'a>(s: &'a str, name: &str) -> Option<&'a str> {
+    s.strip_prefix(name)?
+        .trim_start()
+        .strip_prefix('(')?
+        .strip_suffix(')')
+        .map(str::trim)
+}
+
+fn parse_place<A, B>(
+    s: &str,
+    parse_a: impl Fn(&str) -> Option<A>,
+    parse_b: impl Fn(&str) -> Option<B>,
+) -> Option<(A, Option<B>)> {
     let mut parts = s.split_whitespace();
-    let first = parts.next();
+    let first = parts.next()?;
     let second = parts.next();
-    let mut retval = BorderDef {
-        width: None,
-        color: None,
+    if parts.next().is_some() {
+        return None;
+    }
+    let a = parse_a(first)?;
+    let b = match second {
+        Some(second) => Some(parse_b(second)?),
+        None => None,
     };
-    let mut parse_val = |val: &str| {
-        if let Some(px) = parse_px(val) {
-            retval.width = Some(px);
-            return Some(());
-        } else if let Some(color) = parse_color(val) {
-            retval.color = Some(color);
-            return Some(());
+    Some((a, b))
+}
+
+fn parse_place_content(s: &str) -> Option<(AlignContent, JustifyContent)> {
+    let first = s.split_whitespace().next()?;
+    match parse_place(s, parse_align_content, parse_justify_content)? {
+        (align, Some(justify)) => Some((align, justify)),
+        (align, None) => Some((align, parse_justify_content(first)?)),
+    }
+}
+
+fn parse_place_items(s: &str) -> Option<AlignItems> {
+    let (align, justify) = parse_place(s, parse_align_items, parse_align_items)?;
+    if justify.is_some() {
+        log::warn!("place-items: justify-items is not supported by Floem, using only align-items");
+    }
+    Some(align)
+}
+
+fn parse_place_self(s: &str) -> Option<(AlignItems, AlignItems)> {
+    let (align, justify) = parse_place(s, parse_align_items, parse_align_items)?;
+    Some((align, justify.unwrap_or(align)))
+}
+
+fn parse_length_percentage(s: &str) -> Option<LengthPercentage> {
+    #[allow(clippy::cast_possible_truncation)]
+    match parse_px_pct(s)? {
+        PxPct::Px(px) => Some(LengthPercentage::Length(px as f32)),
+        PxPct::Pct(pct) => Some(LengthPercentage::Percent(pct as f32 / 100.0)),
+    }
+}
+
+fn parse_fr(s: &str) -> Option<f32> {
+    s.strip_suffix("fr")?.trim_end().parse::<f32>().ok()
+}
+
+fn parse_min_track(s: &str) -> Option<MinTrackSizingFunction> {
+    match s {
+        "auto" => Some(MinTrackSizingFunction::Auto),
+        "min-content" => Some(MinTrackSizingFunction::MinContent),
+        "max-content" => Some(MinTrackSizingFunction::MaxContent),
+        _ => parse_length_percentage(s).map(MinTrackSizingFunction::Fixed),
+    }
+}
+
+fn parse_max_track(s: &str) -> Option<MaxTrackSizingFunction> {
+    match s {
+        "auto" => Some(MaxTrackSizingFunction::Auto),
+        "min-content" => Some(MaxTrackSizingFunction::MinContent),
+        "max-content" => Some(MaxTrackSizingFunction::MaxContent),
+        _ => {
+            if let Some(fr) = parse_fr(s) {
+                return Some(MaxTrackSizingFunction::Fraction(fr));
+            }
+            parse_length_percentage(s).map(MaxTrackSizingFunction::Fixed)
         }
-        None
+    }
+}
+
+/// Parses single track like `1fr`, `100px`, `auto` or `minmax(100px, 1fr)`
+fn parse_track(s: &str) -> Option<NonRepeatedTrackSizingFunction> {
+    if let Some(args) = function_args(s, "minmax") {
+        let (min, max) = args.split_once(',')?;
+        return Some(MinMax {
+            min: parse_min_track(min.trim())?,
+            max: parse_max_track(max.trim())?,
+        });
+    }
+    if let Some(arg) = function_args(s, "fit-content") {
+        return Some(MinMax {
+            min: MinTrackSizingFunction::Auto,
+            max: MaxTrackSizingFunction::FitContent(parse_length_percentage(arg)?),
+        });
+    }
+    if let Some(fr) = parse_fr(s) {
+        return Some(MinMax {
+            min: MinTrackSizingFunction::Auto,
+            max: MaxTrackSizingFunction::Fraction(fr),
+        });
+    }
+    Some(MinMax {
+        min: parse_min_track(s)?,
+        max: parse_max_track(s)?,
+    })
+}
+
+/// Parses `repeat(<count | auto-fill | auto-fit>, <tracks>)`
+fn parse_track_repeat(args: &str) -> Option<TrackSizingFunction> {
+    let (count, tracks) = args.split_once(',')?;
+    let count = count.trim();
+    let repetition = match count.parse::<u16>() {
+        Ok(0) => return None,
+        Ok(n) => GridTrackRepetition::Count(n),
+        Err(_) => GridTrackRepetition::try_from(count).ok()?,
     };
-    match (first, second) {
-        (Some(val), None) => {
-            parse_val(val)?;
-        }
-        (Some(f), Some(s)) => {
-            parse_val(f)?;
-            parse_val(s)?;
-        }
-        _ => return None,
+    let tracks = split_outside_parens(tracks, char::is_whitespace)
+        .into_iter()
+        .map(parse_track)
+        .collect::<Option<Vec<_>>>()?;
+    if tracks.is_empty() {
+        return None;
+    }
+    Some(TrackSizingFunction::Repeat(repetition, tracks))
+}
+
+fn parse_grid_template(s: &str) -> Option<Vec<TrackSizingFunction>> {
+    if s == "none" {
+        return Some(Vec::new());
     }
-    Some(retval)
+    split_outside_parens(s, char::is_whitespace)
+        .into_iter()
+        .map(|part| match function_args(part, "repeat") {
+            Some(args) => parse_track_repeat(args),
+            None => parse_track(part).map(TrackSizingFunction::Single),
+        })
+        .collect()
+}
+
+fn parse_grid_auto_tracks(s: &str) -> Option<Vec<NonRepeatedTrackSizingFunction>> {
+    split_outside_parens(s, char::is_whitespace)
+        .into_iter()
+        .map(parse_track)
+        .collect()
+}
+
+/// Parses `auto`, line index like `2` or `-1`, or `span 2`
+fn parse_grid_placement(s: &str) -> Option<GridPlacement> {
+    if s == "auto" {
+        return Some(GridPlacement::Auto);
+    }
+    if let Some(count) = s.strip_prefix("span") {
+        return match count.trim().parse::<u16>() {
+            Ok(0) | Err(_) => None,
+            Ok(count) => Some(span(count)),
+        };
+    }
+    match s.parse::<i16>() {
+        Ok(0) | Err(_) => None,
+        Ok(index) => Some(line(index)),
+    }
+}
+
+/// Parses `<start> [/ <end>]`
+fn parse_grid_line(s: &str) -> Option<Line<GridPlacement>> {
+    let (start, end) = match s.split_once('/') {
+        Some((start, end)) => (start.trim(), parse_grid_placement(end.trim())?),
+        None => (s, GridPlacement::Auto),
+    };
+    Some(Line {
+        start: parse_grid_placement(start)?,
+        end,
+    })
 }
 
 #[cfg(test)]
@@ -924,14 +2765,25 @@ mod tests {
 
     use floem::{
         peniko::Color,
+        style::BoxShadow,
+        taffy::{
+            style_helpers::{line, span},
+            LengthPercentage,
+        },
         unit::{Px, PxPct},
     };
 
     use crate::declaration::{
-        get_rgb_value, parse_box_shadow_5, parse_rgb, parse_rgb_value, parse_rgba, BorderDef,
+        get_rgb_value, parse_background, parse_background_image, parse_box_shadow,
+        parse_box_shadow_5, parse_rgb, parse_rgb_value, parse_rgba, BorderDef, BorderStyle,
     };
 
-    use super::{parse_border, parse_duration, parse_rgb_alpha};
+    use super::{
+        is_unsupported, parse_animation, parse_border, parse_border_colors, parse_border_radius,
+        parse_duration, parse_easing, parse_grid_line, parse_grid_template, parse_keyframe_offset,
+        parse_margin, parse_padding, parse_rgb_alpha, parse_transform, parse_transition,
+        parse_transition_delay, unsupported_hint, Declaration, Sides,
+    };
 
     #[test]
     fn duration() {
@@ -949,12 +2801,31 @@ mod tests {
     #[test]
     #[rustfmt::skip]
     fn border() {
+        use floem::peniko::Brush;
+        use floem::style::{BorderColor, BorderLeft, Style};
+
         let v = parse_border("10px").unwrap();
-        assert!(v == BorderDef { width: Some(Px(10.0)), color: None });
+        assert!(v == BorderDef { width: Some(Px(10.0)), style: None, color: None });
         let v = parse_border("10px red").unwrap();
-        assert!(v == BorderDef { width: Some(Px(10.0)), color: Some(Color::RED) });
+        assert!(v == BorderDef { width: Some(Px(10.0)), style: None, color: Some(Color::RED) });
         let v = parse_border("red").unwrap();
-        assert!(v == BorderDef { width: None, color: Some(Color::RED) });
+        assert!(v == BorderDef { width: None, style: None, color: Some(Color::RED) });
+        let v = parse_border("1px solid #cccccc").unwrap();
+        assert!(v == BorderDef { width: Some(Px(1.0)), style: Some(BorderStyle::Solid), color: Color::parse("#cccccc") });
+        let v = parse_border("dashed blue 2px").unwrap();
+        assert!(v == BorderDef { width: Some(Px(2.0)), style: Some(BorderStyle::Dashed), color: Some(Color::BLUE) });
+        let v = parse_border("none").unwrap();
+        assert!(v == BorderDef { width: None, style: Some(BorderStyle::None), color: None });
+        assert!(parse_border("1px 2px").is_none());
+        assert!(parse_border("1px solid red blue").is_none());
+        assert!(parse_border("").is_none());
+        assert!(is_unsupported("border-top-color"));
+        assert!(Declaration::from_cow((&"border-top-color".into(), &"red".into())).is_none());
+        let style = Style::new().border_color(Color::BLUE);
+        let d = Declaration::from_cow((&"border-left".into(), &"2px solid red".into())).unwrap();
+        let style = d.apply_style(style);
+        assert!(matches!(style.get(BorderColor), Brush::Solid(c) if c == Color::BLUE));
+        assert!(style.get(BorderLeft).0.width == 2.0);
     }
 
     #[test]
@@ -990,14 +2861,43 @@ mod tests {
         assert!(parse_rgba("21, 22, 280, 0.1").is_none());
     }
 
+    #[test]
+    #[rustfmt::skip]
+    fn hsl() {
+        use super::parse_hsl;
+
+        assert!(parse_hsl("0, 100%, 50%") == Some(Color::rgb8(255, 0, 0)));
+        assert!(parse_hsl("120, 100%, 25%") == Some(Color::rgb8(0, 128, 0)));
+        assert!(parse_hsl("0.5turn, 0%, 100%") == Some(Color::rgb8(255, 255, 255)));
+        assert!(parse_hsl("240deg, 100%, 50%, 0.5") == Some(Color::rgba8(0, 0, 255, 127)));
+        assert!(parse_hsl("240, 100, 50").is_none());
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn border_color_functions() {
+        let v = parse_border_colors("rgb(1, 2, 3)").unwrap();
+        assert!(v == Color::rgb8(1, 2, 3));
+        let v = parse_border_colors("rgba(0, 0, 0, 0.5) rgba(0, 0, 0, 0.5)").unwrap();
+        assert!(v == Color::rgba8(0, 0, 0, 127));
+        let v = parse_border_colors("hsl(0, 100%, 50%)").unwrap();
+        assert!(v == Color::RED);
+        let v = parse_border("1px solid rgb(1, 2, 3)").unwrap();
+        assert!(v == BorderDef { width: Some(Px(1.0)), style: Some(BorderStyle::Solid), color: Some(Color::rgb8(1, 2, 3)) });
+        let v = parse_border("hsl(120, 100%, 25%) 2px").unwrap();
+        assert!(v.color == Some(Color::rgb8(0, 128, 0)));
+        let v = parse_border("dashed rgba(0, 0, 0, 0.5)").unwrap();
+        assert!(v.color == Some(Color::rgba8(0, 0, 0, 127)));
+    }
+
     #[test]
     fn find_rgba_value() {
         let (start, end) = get_rgb_value("rgba(21, 22, 23, 0.65)").unwrap();
         assert!(start == 5);
-        assert!(end == 18);
+        assert!(end == 21);
         let (start, end) = get_rgb_value("rgb(21, 22, 23)").unwrap();
         assert!(start == 4);
-        assert!(end == 12);
+        assert!(end == 14);
         assert!(get_rgb_value("rgb(21, 22, 23").is_none());
     }
 
@@ -1016,4 +2916,435 @@ mod tests {
         assert!(v.spread == PxPct::Px(15.0));
         assert!(v.color == Color::GREEN);
     }
+
+    #[test]
+    fn box_shadow_layers() {
+        let v = parse_box_shadow("0px 1px 2px rgba(0, 0, 0, 0.3), 0px 4px 8px 2px black").unwrap();
+        assert!(v.v_offset == PxPct::Px(1.0));
+        assert!(v.blur_radius == PxPct::Px(2.0));
+        let v = parse_box_shadow("inset 0px 1px 2px red, 2px 2px green").unwrap();
+        assert!(v.h_offset == PxPct::Px(2.0));
+        assert!(v.color == Color::GREEN);
+        let v = parse_box_shadow("0px 1px 2px red inset").unwrap();
+        assert!(v == BoxShadow::default());
+        assert!(parse_box_shadow("0px 1px 2px red, nope").is_none());
+        assert!(parse_box_shadow("none").unwrap() == BoxShadow::default());
+    }
+
+    #[test]
+    fn gradients() {
+        use floem::kurbo::Point;
+        use floem::peniko::{Brush, GradientKind};
+
+        let Some(Some(Brush::Gradient(g))) = parse_background_image(
+            "linear-gradient(to right, red, rgba(0, 0, 255, 0.5) 50%, green 200px)",
+        ) else {
+            panic!("expected linear gradient");
+        };
+        assert!(
+            g.kind
+                == GradientKind::Linear {
+                    start: Point::new(0.0, 100.0),
+                    end: Point::new(200.0, 100.0)
+                }
+        );
+        let offsets = g.stops.iter().map(|s| s.offset).collect::<Vec<_>>();
+        assert!(offsets == [0.0, 0.5, 1.0]);
+        assert!(g.stops[1].color == Color::rgba8(0, 0, 255, 127));
+
+        let Some(Some(Brush::Gradient(g))) =
+            parse_background_image("linear-gradient(red 0px, white, blue 40px)")
+        else {
+            panic!("expected linear gradient");
+        };
+        assert!(g.stops[1].offset == 0.5);
+
+        let Some(Some(Brush::Gradient(g))) =
+            parse_background_image("radial-gradient(circle 50px at 10px 20px, red, blue)")
+        else {
+            panic!("expected radial gradient");
+        };
+        assert!(
+            matches!(g.kind, GradientKind::Radial { end_center, end_radius, .. } if end_center == Point::new(10.0, 20.0) && end_radius == 50.0)
+        );
+
+        let Some(Some(Brush::Gradient(g))) =
+            parse_background_image("linear-gradient(to right, red, white, blue)")
+        else {
+            panic!("expected linear gradient");
+        };
+        assert!(
+            g.kind
+                == GradientKind::Linear {
+                    start: Point::new(0.0, 50.0),
+                    end: Point::new(100.0, 50.0)
+                }
+        );
+        let offsets = g.stops.iter().map(|s| s.offset).collect::<Vec<_>>();
+        assert!(offsets == [0.0, 0.5, 1.0]);
+
+        let Some(Some(Brush::Gradient(g))) = parse_background_image("radial-gradient(red, blue)")
+        else {
+            panic!("expected radial gradient");
+        };
+        assert!(
+            matches!(g.kind, GradientKind::Radial { end_center, end_radius, .. } if end_center == Point::new(100.0, 100.0) && end_radius == 100.0)
+        );
+        let offsets = g.stops.iter().map(|s| s.offset).collect::<Vec<_>>();
+        assert!(offsets == [0.0, 1.0]);
+
+        assert!(parse_background_image("linear-gradient(red)").is_none());
+        assert!(parse_background_image("url(image.png)").is_none());
+        assert!(parse_background_image("none") == Some(None));
+        assert!(
+            parse_background("#fff linear-gradient(45deg, red 0px, blue 10px)")
+                .is_some_and(|b| matches!(b, Brush::Gradient(_)))
+        );
+        assert!(parse_background("red") == Some(Brush::Solid(Color::RED)));
+        assert!(parse_background("red none") == Some(Brush::Solid(Color::RED)));
+        assert!(parse_background("none red") == Some(Brush::Solid(Color::RED)));
+    }
+
+    #[test]
+    fn transition() {
+        let v = parse_transition("all 200ms").unwrap();
+        assert!(v.transitions[0].property == "all");
+        assert!(v.transitions[0].duration == Duration::from_millis(200));
+        let v =
+            parse_transition("width 150ms ease-in 50ms, background-color 0.3s steps(4)").unwrap();
+        assert!(v.transitions.len() == 2);
+        assert!(v.transitions[0].property == "width");
+        assert!(v.transitions[0].delay == Duration::from_millis(50));
+        assert!(v.transitions[1].property == "background-color");
+        assert!(v.transitions[1].delay.is_zero());
+        assert!(parse_transition("width 1s spring").is_some());
+        assert!(parse_transition("nope 1s").is_none());
+        let v = parse_transition("opacity 1s, width 1s").unwrap();
+        assert!(v.transitions.len() == 1);
+        assert!(parse_transition("width ease").is_none());
+        assert!(parse_transition("none").unwrap().transitions.is_empty());
+        let v = parse_transition_delay("0s, 100ms").unwrap();
+        assert!(v.as_slice() == [Duration::ZERO, Duration::from_millis(100)]);
+    }
+
+    #[test]
+    fn animation() {
+        use super::{AnimationDirection, IterationCount};
+
+        let v = parse_animation("spin 1s linear infinite").unwrap();
+        assert!(v.name.as_deref() == Some("spin"));
+        assert!(v.duration == Duration::from_secs(1));
+        assert!(v.iteration_count == Some(IterationCount::Infinite));
+        let v = parse_animation("2 alternate-reverse 300ms ease-in 100ms pulse").unwrap();
+        assert!(v.name.as_deref() == Some("pulse"));
+        assert!(v.delay == Duration::from_millis(100));
+        assert!(v.iteration_count == Some(IterationCount::Count(2)));
+        assert!(v.direction == AnimationDirection::AlternateReverse);
+        assert!(parse_animation("none").unwrap().name.is_none());
+        assert!(parse_animation("spin 1s 2s 3s").is_none());
+        assert!(parse_keyframe_offset("from") == Some(0.0));
+        assert!(parse_keyframe_offset("25%") == Some(0.25));
+        assert!(parse_keyframe_offset("120%").is_none());
+    }
+
+    #[test]
+    fn flex() {
+        use super::{parse_flex, parse_flex_flow, FlexDef};
+        use floem::{
+            taffy::{FlexDirection, FlexWrap},
+            unit::PxPctAuto,
+        };
+
+        let v = parse_flex("1").unwrap();
+        assert!(v.grow == 1.0 && v.shrink == 1.0 && v.basis == PxPctAuto::Px(0.0));
+        let v = parse_flex("2 0 10px").unwrap();
+        assert!(v.grow == 2.0 && v.shrink == 0.0 && v.basis == PxPctAuto::Px(10.0));
+        let v = parse_flex("50% 3").unwrap();
+        assert!(v.grow == 3.0 && v.shrink == 1.0 && v.basis == PxPctAuto::Pct(50.0));
+        let v = parse_flex("auto").unwrap();
+        assert!(v.grow == 1.0 && v.basis == PxPctAuto::Auto);
+        let v = parse_flex("none").unwrap();
+        assert!(v.grow == 0.0 && v.shrink == 0.0);
+        assert!(parse_flex("initial") == Some(FlexDef::default()));
+        let v = parse_flex("1 1 0").unwrap();
+        assert!(v.grow == 1.0 && v.shrink == 1.0 && v.basis == PxPctAuto::Px(0.0));
+        let v = parse_flex("1 0").unwrap();
+        assert!(v.grow == 1.0 && v.shrink == 0.0 && v.basis == PxPctAuto::Px(0.0));
+        let v = parse_flex("0 0 auto").unwrap();
+        assert!(v.grow == 0.0 && v.shrink == 0.0 && v.basis == PxPctAuto::Auto);
+        let v = parse_flex("2 1 0%").unwrap();
+        assert!(v.grow == 2.0 && v.shrink == 1.0 && v.basis == PxPctAuto::Pct(0.0));
+        assert!(parse_flex("1 10px 1").is_none());
+        assert!(parse_flex("1 1 1").is_none());
+        assert!(parse_flex("-1").is_none());
+        let v = parse_flex_flow("wrap column").unwrap();
+        assert!(v == (FlexDirection::Column, FlexWrap::Wrap));
+        let v = parse_flex_flow("row-reverse").unwrap();
+        assert!(v == (FlexDirection::RowReverse, FlexWrap::NoWrap));
+        assert!(parse_flex_flow("row column").is_none());
+    }
+
+    #[test]
+    fn typography() {
+        use super::{parse_font, parse_font_family, parse_font_weight, parse_line_height};
+        use floem::text::{LineHeightValue, Weight};
+
+        assert!(parse_font_weight("350") == Some(Weight(350)));
+        assert!(parse_font_weight("1000") == Some(Weight(1000)));
+        assert!(parse_font_weight("bolder") == Some(Weight::BOLD));
+        assert!(parse_font_weight("0").is_none());
+        assert!(parse_font_weight("1001").is_none());
+        let v = parse_font_family("\"Inter Display\", 'Segoe UI' , Helvetica  Neue, system-ui");
+        assert!(
+            v.as_deref() == Some("\"Inter Display\", \"Segoe UI\", \"Helvetica Neue\", sans-serif")
+        );
+        assert!(parse_font_family("\"Inter").is_none());
+        assert!(parse_font_family("Inter,").is_none());
+        assert!(parse_font_family("\"Inter\" Bold").is_none());
+        assert!(parse_line_height("1.4") == Some(Some(LineHeightValue::Normal(1.4))));
+        assert!(parse_line_height("150%") == Some(Some(LineHeightValue::Normal(1.5))));
+        assert!(parse_line_height("20px") == Some(Some(LineHeightValue::Px(20.0))));
+        assert!(parse_line_height("normal") == Some(None));
+        let v = parse_font("italic 600 14px/1.4 \"Inter\", sans-serif").unwrap();
+        assert!(v.style == floem::text::Style::Italic);
+        assert!(v.weight == Weight(600));
+        assert!(v.size == Px(14.0));
+        assert!(v.line_height == Some(LineHeightValue::Normal(1.4)));
+        assert!(v.family == "\"Inter\", sans-serif");
+        let v = parse_font("12px / 16px monospace").unwrap();
+        assert!(v.weight == Weight::NORMAL);
+        assert!(v.line_height == Some(LineHeightValue::Px(16.0)));
+        assert!(parse_font("14px").is_none());
+        assert!(parse_font("bold Inter").is_none());
+    }
+
+    #[test]
+    fn css_wide_keywords() {
+        use super::{CssWideKeyword, Declaration};
+        use floem::style::{FontSize, Style, StyleValue, Width};
+        use floem::unit::PxPctAuto;
+
+        assert!(CssWideKeyword::parse("initial") == Some(CssWideKeyword::Initial));
+        assert!(CssWideKeyword::parse("inherited").is_none());
+        let s = Style::new().width(10.0).font_size(12.0);
+        let v = Declaration::apply_keyword(s.clone(), "width", CssWideKeyword::Initial);
+        assert!(matches!(
+            v.get_style_value(Width),
+            StyleValue::Val(PxPctAuto::Auto)
+        ));
+        let v = Declaration::apply_keyword(s.clone(), "width", CssWideKeyword::Inherit);
+        assert!(matches!(
+            v.get_style_value(Width),
+            StyleValue::Val(PxPctAuto::Px(_))
+        ));
+        let v = Declaration::apply_keyword(s.clone(), "font-size", CssWideKeyword::Inherit);
+        assert!(matches!(v.get_style_value(FontSize), StyleValue::Unset));
+        let v = Declaration::apply_keyword(s, "all", CssWideKeyword::Unset);
+        assert!(matches!(v.get_style_value(Width), StyleValue::Unset));
+        assert!(matches!(v.get_style_value(FontSize), StyleValue::Unset));
+    }
+
+    #[test]
+    fn shorthand_keywords() {
+        use super::{AnimationDirectionProp, AnimationNameProp, CssWideKeyword};
+        use floem::style::{
+            AlignContentProp, AlignSelf, ColGap, JustifyContentProp, JustifySelf, RowGap, Style,
+            StyleValue,
+        };
+
+        let mut s = Style::new();
+        for (k, v) in [
+            ("place-content", "center end"),
+            ("place-self", "start end"),
+            ("gap", "4px 8px"),
+            ("animation", "fade 1s alternate"),
+        ] {
+            s = Declaration::from_cow((&k.into(), &v.into()))
+                .unwrap()
+                .apply_style(s);
+        }
+        for property in ["place-content", "place-self", "gap", "animation"] {
+            s = Declaration::apply_keyword(s, property, CssWideKeyword::Unset);
+        }
+        assert!(matches!(
+            s.get_style_value(AlignContentProp),
+            StyleValue::Unset
+        ));
+        assert!(matches!(
+            s.get_style_value(JustifyContentProp),
+            StyleValue::Unset
+        ));
+        assert!(matches!(s.get_style_value(AlignSelf), StyleValue::Unset));
+        assert!(matches!(s.get_style_value(JustifySelf), StyleValue::Unset));
+        assert!(matches!(s.get_style_value(RowGap), StyleValue::Unset));
+        assert!(matches!(s.get_style_value(ColGap), StyleValue::Unset));
+        assert!(matches!(
+            s.get_style_value(AnimationNameProp),
+            StyleValue::Unset
+        ));
+        assert!(matches!(
+            s.get_style_value(AnimationDirectionProp),
+            StyleValue::Unset
+        ));
+    }
+
+    #[test]
+    fn fill() {
+        use super::parse_fill;
+        use floem::peniko::Brush;
+
+        let v = parse_fill("#ff0000").unwrap();
+        assert!(matches!(v, Some(Brush::Solid(c)) if c == Color::rgb8(255, 0, 0)));
+        assert!(parse_fill("currentColor").unwrap().is_none());
+        let v = parse_fill("none").unwrap();
+        assert!(matches!(v, Some(Brush::Solid(c)) if c == Color::TRANSPARENT));
+        let v = parse_fill("linear-gradient(red, blue 24px)").unwrap();
+        assert!(matches!(v, Some(Brush::Gradient(_))));
+        assert!(parse_fill("nope").is_none());
+    }
+
+    #[test]
+    fn editor_properties() {
+        use super::Declaration;
+        use std::borrow::Cow;
+
+        let parse = |k: &'static str, v: &'static str| {
+            Declaration::from_cow((&Cow::Borrowed(k), &Cow::Borrowed(v)))
+        };
+        assert!(matches!(
+            parse("editor-current-line-color", "none"),
+            Some(Declaration::EditorCurrentLineColor(None))
+        ));
+        assert!(matches!(
+            parse("editor-gutter-color", "#888"),
+            Some(Declaration::EditorGutterColor(Some(_)))
+        ));
+        assert!(matches!(
+            parse("editor-scroll-beyond-last-line", "true"),
+            Some(Declaration::EditorScrollBeyondLastLine(true))
+        ));
+        assert!(parse("editor-indent-guide", "yes").is_none());
+    }
+
+    #[test]
+    fn reduced_motion() {
+        use crate::media::{self, MediaFeatures};
+
+        media::set(MediaFeatures {
+            reduced_motion: true,
+            more_contrast: false,
+        });
+        assert!(parse_transition("width 1s").unwrap().transitions.is_empty());
+        assert!(parse_transition("nope 1s").is_none());
+        assert!(parse_animation("spin 1s").unwrap().name.is_none());
+        media::set(MediaFeatures::default());
+    }
+
+    #[test]
+    fn transform() {
+        let v = parse_transform("translate(4px, -2px) translateY(2px) scale(1.5) rotate(90deg)")
+            .unwrap();
+        assert!(v.translate == (4.0, 0.0));
+        assert!(v.scale == (1.5, 1.5));
+        assert!(v.rotate == 90.0);
+        let v = parse_transform("scaleX(50%) rotate(0.25turn) rotate(-45deg)").unwrap();
+        assert!(v.scale == (0.5, 1.0));
+        assert!(v.rotate == 45.0);
+        assert!(parse_transform("none").is_some());
+        assert!(parse_transform("translate(10%)").is_none());
+        assert!(parse_transform("skew(10deg)").is_none());
+        assert!(parse_transform("scale(1.5) translateY(2px)").is_none());
+        assert!(parse_transform("rotate(90deg) translate(4px)").is_none());
+    }
+
+    #[test]
+    fn easing() {
+        let v = parse_easing("cubic-bezier(0.1, 0.7, 1.0, 0.1)").unwrap();
+        assert!(v.eval(1.0) == 1.0);
+        assert!(parse_easing("cubic-bezier(0.1, 0.7, 1.5, 0.1)").is_none());
+        let v = parse_easing("steps(4, jump-end)").unwrap();
+        assert!(v.eval(0.3) == 0.25);
+        let v = parse_easing("steps(4, start)").unwrap();
+        assert!(v.eval(0.3) == 0.5);
+        assert!(parse_easing("spring(1, 150, 5)").is_some());
+        assert!(parse_easing("steps(0)").is_none());
+    }
+
+    #[test]
+    fn grid_template() {
+        use floem::taffy::{
+            GridTrackRepetition, MaxTrackSizingFunction, MinTrackSizingFunction,
+            TrackSizingFunction,
+        };
+        let v = parse_grid_template("200px 1fr auto").unwrap();
+        assert!(v.len() == 3);
+        let TrackSizingFunction::Single(track) = &v[1] else {
+            panic!("expected single track");
+        };
+        assert!(track.max == MaxTrackSizingFunction::Fraction(1.0));
+        let v = parse_grid_template("repeat(3, minmax(100px, 1fr))").unwrap();
+        let TrackSizingFunction::Repeat(GridTrackRepetition::Count(3), tracks) = &v[0] else {
+            panic!("expected repeat");
+        };
+        assert!(tracks[0].min == MinTrackSizingFunction::Fixed(LengthPercentage::Length(100.0)));
+        let v = parse_grid_template("repeat(auto-fill, 120px) 50%").unwrap();
+        assert!(v.len() == 2);
+        assert!(parse_grid_template("none").unwrap().is_empty());
+        assert!(parse_grid_template("repeat(0, 1fr)").is_none());
+        assert!(parse_grid_template("1fr foo").is_none());
+    }
+
+    #[test]
+    fn grid_line() {
+        use floem::taffy::GridPlacement;
+        let v = parse_grid_line("1 / 3").unwrap();
+        assert!(v.start == line(1) && v.end == line(3));
+        let v = parse_grid_line("span 2").unwrap();
+        assert!(v.start == span(2) && v.end == GridPlacement::Auto);
+        let v = parse_grid_line("auto / -1").unwrap();
+        assert!(v.start == GridPlacement::Auto && v.end == line(-1));
+        assert!(parse_grid_line("0").is_none());
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn sides_shorthand() {
+        use floem::unit::PxPctAuto;
+        let v = parse_padding("4px").unwrap();
+        assert!(v == Sides { top: PxPct::Px(4.0), right: PxPct::Px(4.0), bottom: PxPct::Px(4.0), left: PxPct::Px(4.0) });
+        let v = parse_padding("4px 10%").unwrap();
+        assert!(v == Sides { top: PxPct::Px(4.0), right: PxPct::Pct(10.0), bottom: PxPct::Px(4.0), left: PxPct::Pct(10.0) });
+        let v = parse_margin("0px auto 8px").unwrap();
+        assert!(v == Sides { top: PxPctAuto::Px(0.0), right: PxPctAuto::Auto, bottom: PxPctAuto::Px(8.0), left: PxPctAuto::Auto });
+        let v = parse_margin("1px 2px 3px 4px").unwrap();
+        assert!(v == Sides { top: PxPctAuto::Px(1.0), right: PxPctAuto::Px(2.0), bottom: PxPctAuto::Px(3.0), left: PxPctAuto::Px(4.0) });
+        assert!(parse_margin("1px 2px 3px 4px 5px").is_none());
+        assert!(parse_padding("auto").is_none());
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn border_radius() {
+        use floem::style::{BorderRadius, Style, StyleValue};
+        use std::borrow::Cow;
+
+        assert!(parse_border_radius("4px") == Some(PxPct::Px(4.0)));
+        assert!(parse_border_radius("4px 4px 4px 4px") == Some(PxPct::Px(4.0)));
+        assert!(parse_border_radius("50% 50%") == Some(PxPct::Pct(50.0)));
+        assert!(parse_border_radius("8px 0px").is_none());
+        assert!(parse_border_radius("1px 1px 1px 50%").is_none());
+        assert!(parse_border_radius("").is_none());
+        assert!(parse_border_radius("10px / 5px").is_none());
+        assert!(parse_border_radius("1px 2px 3px 4px 5px").is_none());
+        // Corner longhand must not change other corners
+        let mut style = Style::new();
+        for (k, v) in [("border-radius", "12px"), ("border-bottom-left-radius", "0")] {
+            if let Some(d) = Declaration::from_cow((&Cow::Borrowed(k), &Cow::Borrowed(v))) {
+                style = d.apply_style(style);
+            }
+        }
+        assert!(is_unsupported("border-bottom-left-radius"));
+        assert!(unsupported_hint("border-bottom-left-radius").is_some());
+        assert!(matches!(style.get_style_value(BorderRadius), StyleValue::Val(PxPct::Px(r)) if r == 12.0));
+    }
 }
@@ -1,5 +1,7 @@
 pub enum Token<'a> {
     Selector { value: &'a str, line: usize },
+    AtRule { value: &'a str, line: usize },
+    AtRuleClose { line: usize },
     Property { value: &'a str, line: usize },
     Value { value: &'a str, line: usize },
     BlockOpen { line: usize },
@@ -13,6 +15,8 @@ impl std::fmt::Debug for Token<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Token::Selector { value, .. } => write!(f, "Selector({value})"),
+            Token::AtRule { value, .. } => write!(f, "AtRule({value})"),
+            Token::AtRuleClose { .. } => write!(f, "AtRuleClose"),
             Token::Property { value, .. } => write!(f, "Property({value})"),
             Token::Value { value, .. } => write!(f, "Value({value})"),
             Token::BlockOpen { .. } => write!(f, "BlockOpen"),
@@ -61,6 +65,15 @@ impl<'a> Lexer<'a> {
         }
     }
 
+    fn advance_until_block_open(&mut self) {
+        while let Some(peek) = self.peek_char() {
+            if peek == '{' || peek == ';' {
+                break;
+            }
+            self.advance();
+        }
+    }
+
     #[allow(clippy::while_let_loop)]
     fn advance_until_comment_end(&mut self) {
         loop {
@@ -98,6 +111,9 @@ impl<'a> Lexer<'a> {
     pub fn tokens(&mut self) -> Vec<Token<'a>> {
         let mut tokens = Vec::with_capacity(1024 * 16); // Some size
         let mut inside_block = false;
+        // `@name prelude {` seen, its block contains rules instead of declarations
+        let mut at_rule_pending = false;
+        let mut inside_at_rule = false;
         let mut start_pos;
         loop {
             let Some(c) = self.advance() else {
@@ -107,12 +123,38 @@ impl<'a> Lexer<'a> {
             match c {
                 '{' => {
                     tokens.push(Token::BlockOpen { line: self.line });
-                    inside_block = true;
+                    if at_rule_pending {
+                        at_rule_pending = false;
+                        inside_at_rule = true;
+                    } else {
+                        inside_block = true;
+                    }
+                }
+                '}' if !inside_block && inside_at_rule => {
+                    tokens.push(Token::AtRuleClose { line: self.line });
+                    inside_at_rule = false;
                 }
                 '}' => {
                     tokens.push(Token::BlockClose { line: self.line });
                     inside_block = false;
                 }
+                '@' if !inside_block => {
+                    start_pos = self.position;
+                    // Block can open on a later line
+                    let line = self.line;
+                    self.advance_until_block_open();
+                    tokens.push(Token::AtRule {
+                        line,
+                        value: self.input[start_pos..self.position].trim_end(),
+                    });
+                    // Statement at-rule like `@import "file";` has no block
+                    if self.peek_char() == Some(';') {
+                        self.advance();
+                        tokens.push(Token::AtRuleClose { line: self.line });
+                    } else {
+                        at_rule_pending = true;
+                    }
+                }
                 ':' if inside_block => {
                     tokens.push(Token::Colon { line: self.line });
                 }
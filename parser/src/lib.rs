@@ -1,14 +1,19 @@
 pub mod analyzer;
 pub mod declaration;
 pub mod lexer;
+pub mod logical;
+pub mod media;
 mod parser;
 mod read;
+pub mod units;
 
 use analyzer::{analyze_tokens, SyntaxError};
 use lexer::Lexer;
 use parser::{replace_vars, Parser};
 
-pub use parser::{PseudoClass, Rule};
+pub use parser::{
+    AtRule, PseudoClass, PseudoElement, Rule, Selector, ROOT_SELECTOR, UNIVERSAL_SELECTOR,
+};
 pub use read::read_styles;
 
 #[must_use]
@@ -18,7 +23,7 @@ pub fn css_to_rules(input: &str) -> Vec<Rule<'_>> {
     replace_vars(rules)
 }
 
-pub fn analyze(input: &str) -> Vec<SyntaxError> {
+pub fn analyze(input: &str) -> Vec<SyntaxError<'_>> {
     let tokens = Lexer::new(input).tokens();
     analyze_tokens(&tokens, input)
 }
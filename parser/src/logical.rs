@@ -0,0 +1,155 @@
+use std::cell::Cell;
+
+use floem::taffy::FlexDirection;
+use smallvec::SmallVec;
+
+/// Inline base direction that logical properties are resolved with
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Direction {
+    #[default]
+    Ltr,
+    Rtl,
+}
+
+thread_local! {
+    static DIRECTION: Cell<Direction> = const { Cell::new(Direction::Ltr) };
+}
+
+/// Direction used by parsers on this thread
+#[must_use]
+pub fn current() -> Direction {
+    DIRECTION.with(Cell::get)
+}
+
+/// Sets direction used by parsers on this thread
+pub fn set(direction: Direction) {
+    DIRECTION.with(|d| d.set(direction));
+}
+
+/// Logical property split into physical parts
+struct Logical<'a> {
+    /// `margin`, `padding`, `inset` or `border`
+    base: &'a str,
+    start: &'static str,
+    end: &'static str,
+    /// `None` for two sided shorthand
+    side: Option<&'static str>,
+    /// `-color` or `-width` of border
+    suffix: &'a str,
+}
+
+impl Logical<'_> {
+    fn physical(&self, side: &str) -> String {
+        if self.base == "inset" {
+            side.to_string()
+        } else {
+            format!("{}-{side}{}", self.base, self.suffix)
+        }
+    }
+}
+
+fn parse_logical(property: &str) -> Option<Logical<'_>> {
+    let (base, rest, (start, end)) = if let Some((base, rest)) = property.split_once("-inline") {
+        let sides = match current() {
+            Direction::Ltr => ("left", "right"),
+            Direction::Rtl => ("right", "left"),
+        };
+        (base, rest, sides)
+    } else {
+        let (base, rest) = property.split_once("-block")?;
+        (base, rest, ("top", "bottom"))
+    };
+    let (side, suffix) = if let Some(suffix) = rest.strip_prefix("-start") {
+        (Some(start), suffix)
+    } else if let Some(suffix) = rest.strip_prefix("-end") {
+        (Some(end), suffix)
+    } else {
+        (None, rest)
+    };
+    let valid = match base {
+        "margin" | "padding" | "inset" => suffix.is_empty(),
+        "border" => matches!(suffix, "" | "-color" | "-width"),
+        _ => false,
+    };
+    valid.then_some(Logical {
+        base,
+        start,
+        end,
+        side,
+        suffix,
+    })
+}
+
+/// Expands logical property to physical properties and values, `None` if
+/// `property` is not logical. Two sided shorthands take `<start> <end>?` values,
+/// `border-inline` and `border-block` take one border for both sides.
+#[must_use]
+pub fn expand<'a>(property: &str, value: &'a str) -> Option<SmallVec<[(String, &'a str); 2]>> {
+    let logical = parse_logical(property)?;
+    if let Some(side) = logical.side {
+        return Some(SmallVec::from_iter([(logical.physical(side), value)]));
+    }
+    let parts = value.split_whitespace().collect::<SmallVec<[_; 2]>>();
+    let (start, end) = match parts.as_slice() {
+        [start, end] if logical.base != "border" || !logical.suffix.is_empty() => (*start, *end),
+        _ => (value, value),
+    };
+    Some(SmallVec::from_iter([
+        (logical.physical(logical.start), start),
+        (logical.physical(logical.end), end),
+    ]))
+}
+
+/// Physical property to transition for logical `property`, two sided shorthands
+/// transition the physical shorthand
+#[must_use]
+pub fn physical_property(property: &str) -> Option<String> {
+    let logical = parse_logical(property)?;
+    Some(match logical.side {
+        Some(side) => logical.physical(side),
+        None => format!("{}{}", logical.base, logical.suffix),
+    })
+}
+
+/// Rows follow the inline direction, so they are reversed in right-to-left layout
+#[must_use]
+pub fn flex_direction(direction: FlexDirection) -> FlexDirection {
+    match (current(), direction) {
+        (Direction::Rtl, FlexDirection::Row) => FlexDirection::RowReverse,
+        (Direction::Rtl, FlexDirection::RowReverse) => FlexDirection::Row,
+        _ => direction,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{expand, physical_property, set, Direction};
+
+    #[test]
+    fn logical_properties() {
+        let v = expand("margin-inline-start", "4px").unwrap();
+        assert_eq!(v.as_slice(), [("margin-left".to_string(), "4px")]);
+        let v = expand("padding-inline", "4px 8px").unwrap();
+        assert_eq!(v[0], ("padding-left".to_string(), "4px"));
+        assert_eq!(v[1], ("padding-right".to_string(), "8px"));
+        let v = expand("border-inline", "1px solid red").unwrap();
+        assert_eq!(v[1], ("border-right".to_string(), "1px solid red"));
+        let v = expand("inset-block-end", "0").unwrap();
+        assert_eq!(v[0], ("bottom".to_string(), "0"));
+        assert!(expand("margin-left", "4px").is_none());
+        assert!(expand("margin-inline-color", "red").is_none());
+        assert!(expand("outline-inline", "red").is_none());
+        set(Direction::Rtl);
+        let v = expand("border-inline-start-color", "red").unwrap();
+        assert_eq!(v[0], ("border-right-color".to_string(), "red"));
+        let v = expand("margin-inline", "1px 2px").unwrap();
+        assert_eq!(v[0], ("margin-right".to_string(), "1px"));
+        assert_eq!(v[1], ("margin-left".to_string(), "2px"));
+        assert_eq!(physical_property("inset-inline-start").unwrap(), "right");
+        assert_eq!(
+            physical_property("border-inline-width").unwrap(),
+            "border-width"
+        );
+        set(Direction::Ltr);
+    }
+}
@@ -0,0 +1,121 @@
+use std::cell::Cell;
+
+/// User preferences that `@media` queries are matched against.
+///
+/// Floem doesn't report these from the system, so the app sets them on the provider.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MediaFeatures {
+    /// Matches `(prefers-reduced-motion: reduce)`, transitions and animations are disabled
+    pub reduced_motion: bool,
+    /// Matches `(prefers-contrast: more)`
+    pub more_contrast: bool,
+}
+
+thread_local! {
+    static FEATURES: Cell<MediaFeatures> = const {
+        Cell::new(MediaFeatures {
+            reduced_motion: false,
+            more_contrast: false,
+        })
+    };
+}
+
+/// Media features used by parsers on this thread
+#[must_use]
+pub fn current() -> MediaFeatures {
+    FEATURES.with(Cell::get)
+}
+
+/// Sets media features used by parsers on this thread
+pub fn set(features: MediaFeatures) {
+    FEATURES.with(|f| f.set(features));
+}
+
+/// Media query of `@media` rule, features are joined with `and`.
+/// `None` features match any value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MediaQuery {
+    pub reduced_motion: Option<bool>,
+    pub more_contrast: Option<bool>,
+}
+
+impl MediaQuery {
+    /// Parses query in form of `[screen|all] [and] (<feature>[: <value>]) [and (...)]`.
+    /// Feature without value matches any value but `no-preference`.
+    #[must_use]
+    pub fn parse_str(s: &str) -> Option<Self> {
+        let mut query = Self::default();
+        let mut rest = s.trim();
+        if let Some(r) = rest.strip_prefix("only ") {
+            rest = r.trim_start();
+        }
+        for media_type in ["screen", "all"] {
+            if let Some(r) = rest.strip_prefix(media_type) {
+                rest = r.trim_start();
+                rest = rest.strip_prefix("and").unwrap_or(rest).trim_start();
+            }
+        }
+        while !rest.is_empty() {
+            let r = rest.strip_prefix('(')?;
+            let (feature, r) = r.split_once(')')?;
+            let (name, value) = feature.split_once(':').unwrap_or((feature, ""));
+            match (name.trim(), value.trim()) {
+                ("prefers-reduced-motion", "reduce" | "") => query.reduced_motion = Some(true),
+                ("prefers-reduced-motion", "no-preference") => query.reduced_motion = Some(false),
+                ("prefers-contrast", "more" | "") => query.more_contrast = Some(true),
+                ("prefers-contrast", "no-preference") => query.more_contrast = Some(false),
+                _ => return None,
+            }
+            rest = r.trim_start();
+            if !rest.is_empty() {
+                rest = rest.strip_prefix("and")?.trim_start();
+            }
+        }
+        Some(query)
+    }
+
+    #[must_use]
+    pub fn matches(&self, features: MediaFeatures) -> bool {
+        self.reduced_motion
+            .map_or(true, |v| v == features.reduced_motion)
+            && self
+                .more_contrast
+                .map_or(true, |v| v == features.more_contrast)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MediaFeatures, MediaQuery};
+
+    #[test]
+    fn media_query() {
+        let q = MediaQuery::parse_str("(prefers-reduced-motion: reduce)").unwrap();
+        assert_eq!(q.reduced_motion, Some(true));
+        assert!(q.more_contrast.is_none());
+        let q = MediaQuery::parse_str(
+            "screen and (prefers-contrast: more) and (prefers-reduced-motion: no-preference)",
+        )
+        .unwrap();
+        assert_eq!(q.more_contrast, Some(true));
+        assert_eq!(q.reduced_motion, Some(false));
+        let features = MediaFeatures {
+            reduced_motion: false,
+            more_contrast: true,
+        };
+        assert!(q.matches(features));
+        assert!(!q.matches(MediaFeatures::default()));
+        let q = MediaQuery::parse_str("(prefers-reduced-motion)").unwrap();
+        assert_eq!(q.reduced_motion, Some(true));
+        assert!(!q.matches(MediaFeatures::default()));
+        assert_eq!(
+            MediaQuery::parse_str("(prefers-contrast)")
+                .unwrap()
+                .more_contrast,
+            Some(true)
+        );
+        assert!(MediaQuery::parse_str("print").is_none());
+        assert!(MediaQuery::parse_str("(min-width: 600px)").is_none());
+        assert!(MediaQuery::parse_str("(prefers-contrast: less)").is_none());
+    }
+}
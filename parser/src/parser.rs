@@ -3,6 +3,7 @@ use std::{borrow::Cow, iter::Zip};
 use smallvec::SmallVec;
 
 use crate::lexer::Token;
+use crate::media::MediaQuery;
 
 /// Parser that turns lexer tokens into `ParserToken` and builds
 /// a `Rule`
@@ -11,7 +12,9 @@ pub struct Parser<'a> {
 }
 
 pub enum ParserToken<'a> {
-    Selector { value: &'a str },
+    Selector { value: &'a str, line: usize },
+    AtRule { value: &'a str, line: usize },
+    AtRuleClose,
     Property { value: &'a str },
     Value { value: &'a str },
 }
@@ -20,7 +23,9 @@ impl<'a> ParserToken<'a> {
     #[must_use]
     pub const fn from_token(token: &Token<'a>) -> Option<Self> {
         match token {
-            Token::Selector { value, .. } => Some(ParserToken::Selector { value }),
+            Token::Selector { value, line } => Some(ParserToken::Selector { value, line: *line }),
+            Token::AtRule { value, line } => Some(ParserToken::AtRule { value, line: *line }),
+            Token::AtRuleClose { .. } => Some(ParserToken::AtRuleClose),
             Token::Property { value, .. } => Some(ParserToken::Property { value }),
             Token::Value { value, .. } => Some(ParserToken::Value { value }),
             _ => None,
@@ -37,8 +42,9 @@ pub enum PseudoClass {
     DisabledHover,
     Focus,
     FocusHover,
-    Placeholder,
-    Selection,
+    FocusVisible,
+    Selected,
+    SelectedHover,
 }
 
 impl PseudoClass {
@@ -47,10 +53,28 @@ impl PseudoClass {
             b":hover" => Self::Hover,
             b":focus" => Self::Focus,
             b":focus:hover" => Self::FocusHover,
+            b":focus-visible" => Self::FocusVisible,
+            b":selected" | b":checked" => Self::Selected,
+            b":selected:hover" | b":checked:hover" => Self::SelectedHover,
             b":active" => Self::Active,
             b":active:hover" => Self::ActiveHover,
             b":disabled" => Self::Disabled,
             b":disabled:hover" => Self::DisabledHover,
+            _ => return None,
+        };
+        Some(val)
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum PseudoElement {
+    Placeholder,
+    Selection,
+}
+
+impl PseudoElement {
+    pub const fn parse_str(s: &str) -> Option<Self> {
+        let val = match s.as_bytes() {
             b"::placeholder" => Self::Placeholder,
             b"::selection" => Self::Selection,
             _ => return None,
@@ -59,59 +83,108 @@ impl PseudoClass {
     }
 }
 
+/// At-rule block that contains the rule
+#[derive(Clone, Copy)]
+pub enum AtRule<'a> {
+    /// `@keyframes <name>`, selectors of the rule are keyframe offsets
+    Keyframes(&'a str),
+    /// `@media <query>`, rule applies when query matches the media features
+    Media(MediaQuery),
+}
+
+impl<'a> AtRule<'a> {
+    /// Parses at-rule without `@`, e.g. `keyframes spin`
+    #[must_use]
+    pub fn parse_str(s: &'a str) -> Option<Self> {
+        let (name, prelude) = s.split_once(char::is_whitespace).unwrap_or((s, ""));
+        let prelude = prelude.trim();
+        match name {
+            "keyframes" if !prelude.is_empty() => Some(Self::Keyframes(prelude)),
+            "media" => MediaQuery::parse_str(prelude).map(Self::Media),
+            _ => None,
+        }
+    }
+}
+
+/// Selector that matches every element styled with `.css()`
+pub const UNIVERSAL_SELECTOR: &str = "*";
+
+/// Selector for document level declarations and variables
+pub const ROOT_SELECTOR: &str = ":root";
+
+/// Selector in form of `name[::pseudo-element][:pseudo-class]`
 pub struct Selector<'a> {
     pub selector: &'a str,
+    pub pseudo_element: Option<PseudoElement>,
     pub pseudo_class: Option<PseudoClass>,
 }
 
-fn split_value(value: &str) -> Selector {
-    if let Some(colon_column) = value.find(':') {
-        Selector {
-            selector: &value[..colon_column],
-            pseudo_class: PseudoClass::parse_str(&value[colon_column..]),
-        }
-    } else {
-        Selector {
-            selector: value,
-            pseudo_class: None,
-        }
+/// Splits `::element:state` into `::element` and `:state`
+fn split_pseudo_element(value: &str) -> (&str, &str) {
+    // ::placeholder:hover
+    //              ^ find this
+    match value[2..].find(':') {
+        Some(colon_column) => value.split_at(colon_column + 2),
+        None => (value, ""),
     }
 }
 
-#[cold]
-fn split_double_colon(value: &str) -> Selector {
-    // ::whatever:hover
-    //           ^ find this
-    if let Some(colon_column) = value[2..].find(':') {
-        Selector {
-            selector: &value[..colon_column],
-            pseudo_class: PseudoClass::parse_str(&value[colon_column..]),
-        }
-    } else {
-        Selector {
+fn split_value(value: &str) -> Result<Selector<'_>, &str> {
+    let Some(colon_column) = value.find(':') else {
+        return Ok(Selector {
             selector: value,
+            pseudo_element: None,
             pseudo_class: None,
-        }
-    }
+        });
+    };
+    let (selector, pseudo) = value.split_at(colon_column);
+    // Bare `:hover` or `::selection` targets every element like in css
+    let selector = if selector.is_empty() {
+        UNIVERSAL_SELECTOR
+    } else {
+        selector
+    };
+    let (element, state) = if pseudo.starts_with("::") {
+        split_pseudo_element(pseudo)
+    } else {
+        ("", pseudo)
+    };
+    let pseudo_element = if element.is_empty() {
+        None
+    } else {
+        Some(PseudoElement::parse_str(element).ok_or(element)?)
+    };
+    let pseudo_class = if state.is_empty() {
+        None
+    } else {
+        Some(PseudoClass::parse_str(state).ok_or(state)?)
+    };
+    Ok(Selector {
+        selector,
+        pseudo_element,
+        pseudo_class,
+    })
 }
 
-impl<'a> From<&'a str> for Selector<'a> {
+impl<'a> TryFrom<&'a str> for Selector<'a> {
+    /// Unrecognized pseudo-class or pseudo-element part of the selector
+    type Error = &'a str;
+
     #[inline]
-    fn from(value: &'a str) -> Self {
-        if value == ":root" {
-            return Self {
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        if value == ROOT_SELECTOR {
+            return Ok(Self {
                 selector: value,
+                pseudo_element: None,
                 pseudo_class: None,
-            };
-        }
-        if value.starts_with("::") {
-            return split_double_colon(value);
+            });
         }
         split_value(value)
     }
 }
 
 pub struct Rule<'a> {
+    pub at_rule: Option<AtRule<'a>>,
     pub selectors: SmallVec<[Selector<'a>; 4]>,
     pub properties: SmallVec<[Cow<'a, str>; 4]>,
     pub values: SmallVec<[Cow<'a, str>; 4]>,
@@ -121,6 +194,7 @@ impl Rule<'_> {
     #[must_use]
     pub const fn new_const() -> Self {
         Self {
+            at_rule: None,
             selectors: SmallVec::<[Selector<'_>; 4]>::new_const(),
             properties: SmallVec::<[Cow<'_, str>; 4]>::new_const(),
             values: SmallVec::<[Cow<'_, str>; 4]>::new_const(),
@@ -161,30 +235,71 @@ impl<'a> Parser<'a> {
             .iter()
             .filter_map(ParserToken::from_token)
             .peekable();
+        let mut at_rule = None;
+        let mut skip_at_rule = false;
         'main: loop {
             let Some(token) = tokens.next() else {
                 break 'main;
             };
 
-            let ParserToken::Selector { value: selector } = token else {
-                continue 'main;
+            let (selector, line) = match token {
+                ParserToken::Selector { value, line } => (value, line),
+                ParserToken::AtRule { value, line } => {
+                    at_rule = AtRule::parse_str(value);
+                    skip_at_rule = at_rule.is_none();
+                    if skip_at_rule {
+                        log::warn!("Unsupported at-rule '@{value}' at line {line}, skipped");
+                    }
+                    continue 'main;
+                }
+                ParserToken::AtRuleClose => {
+                    at_rule = None;
+                    skip_at_rule = false;
+                    continue 'main;
+                }
+                _ => continue 'main,
             };
 
             let mut rule = Rule::new_const();
+            rule.at_rule = at_rule;
             props.clear();
-            rule.selectors
-                .extend(selector.split(',').map(str::trim).map(Selector::from));
+            let selectors = selector
+                .split(',')
+                .map(str::trim)
+                .map(Selector::try_from)
+                .collect::<Result<SmallVec<_>, _>>();
+            let skip = match selectors {
+                Ok(selectors) => {
+                    rule.selectors = selectors;
+                    false
+                }
+                Err(pseudo) => {
+                    log::warn!(
+                        "Unknown pseudo-class '{pseudo}' in '{}' at line {line}, rule skipped",
+                        selector.trim()
+                    );
+                    true
+                }
+            };
             'props: loop {
                 let Some(peek) = tokens.peek() else {
                     break 'props;
                 };
-                if matches!(peek, ParserToken::Selector { .. }) {
+                if matches!(
+                    peek,
+                    ParserToken::Selector { .. }
+                        | ParserToken::AtRule { .. }
+                        | ParserToken::AtRuleClose
+                ) {
                     break 'props;
                 }
                 if let Some(next) = tokens.next() {
                     props.push(next);
                 }
             }
+            if skip || skip_at_rule {
+                continue 'main;
+            }
             for chunk in props.chunks_exact(2) {
                 if let [ParserToken::Property { value: prop_value }, ParserToken::Value { value }] =
                     chunk
@@ -202,7 +317,7 @@ impl<'a> Parser<'a> {
 pub(crate) fn replace_vars(mut rules: Vec<Rule<'_>>) -> Vec<Rule<'_>> {
     let Some(root_idx) = rules
         .iter()
-        .position(|rule| rule.selectors.iter().any(|s| s.selector == ":root"))
+        .position(|rule| rule.selectors.iter().any(|s| s.selector == ROOT_SELECTOR))
     else {
         return rules;
     };
@@ -280,22 +395,27 @@ fn get_var_name(value: &str) -> &str {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::lexer::Lexer;
 
     #[test]
     fn replace_vars_ok() {
         // Setup initial rules with variables
         let rules = vec![
             Rule {
+                at_rule: None,
                 selectors: SmallVec::from_vec(vec![Selector {
                     selector: ":root",
+                    pseudo_element: None,
                     pseudo_class: None,
                 }]),
                 properties: SmallVec::from_vec(vec![Cow::Borrowed("--main-color")]),
                 values: SmallVec::from_vec(vec![Cow::Borrowed("blue")]),
             },
             Rule {
+                at_rule: None,
                 selectors: SmallVec::from_vec(vec![Selector {
                     selector: ".button",
+                    pseudo_element: None,
                     pseudo_class: None,
                 }]),
                 properties: SmallVec::from_vec(vec![Cow::Borrowed("background-color")]),
@@ -309,4 +429,93 @@ mod tests {
         // Check that the variable was replaced correctly
         assert_eq!(updated_rules[1].values[0], Cow::Borrowed("blue"));
     }
+
+    #[test]
+    fn pseudo_class_selected_focus_visible() {
+        assert!(matches!(
+            PseudoClass::parse_str(":selected"),
+            Some(PseudoClass::Selected)
+        ));
+        assert!(matches!(
+            PseudoClass::parse_str(":checked"),
+            Some(PseudoClass::Selected)
+        ));
+        assert!(matches!(
+            PseudoClass::parse_str(":checked:hover"),
+            Some(PseudoClass::SelectedHover)
+        ));
+        assert!(matches!(
+            PseudoClass::parse_str(":focus-visible"),
+            Some(PseudoClass::FocusVisible)
+        ));
+    }
+
+    #[test]
+    fn unknown_pseudo_class_skips_rule() {
+        let tokens =
+            Lexer::new("button:hoverr {\n    color: red;\n}\nlabel {\n    color: blue;\n}\n")
+                .tokens();
+        let rules = Parser::new(tokens).parse();
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].selectors[0].selector, "label");
+        assert!(Selector::try_from("button::placeholderr").is_err());
+        assert!(Selector::try_from("button:hover").is_ok());
+    }
+
+    #[test]
+    fn pseudo_element_with_state() {
+        let s = Selector::try_from("input::placeholder:hover").unwrap();
+        assert_eq!(s.selector, "input");
+        assert!(matches!(s.pseudo_element, Some(PseudoElement::Placeholder)));
+        assert!(matches!(s.pseudo_class, Some(PseudoClass::Hover)));
+        let s = Selector::try_from("editor::selection:focus:hover").unwrap();
+        assert_eq!(s.selector, "editor");
+        assert!(matches!(s.pseudo_element, Some(PseudoElement::Selection)));
+        assert!(matches!(s.pseudo_class, Some(PseudoClass::FocusHover)));
+        let s = Selector::try_from("input::placeholder").unwrap();
+        assert!(matches!(s.pseudo_element, Some(PseudoElement::Placeholder)));
+        assert!(s.pseudo_class.is_none());
+        assert!(Selector::try_from("input::placeholder:hoverr").is_err());
+    }
+
+    #[test]
+    fn keyframes_at_rule() {
+        let input = "@import \"other.css\";\n@keyframes pulse {\n    from {\n        width: 10px;\n    }\n    50%, 75% {\n        width: 20px;\n    }\n}\n@media print {\n    label {\n        color: red;\n    }\n}\nlabel {\n    color: blue;\n}\n";
+        let rules = Parser::new(Lexer::new(input).tokens()).parse();
+        assert_eq!(rules.len(), 3);
+        assert!(matches!(rules[0].at_rule, Some(AtRule::Keyframes("pulse"))));
+        assert_eq!(rules[0].selectors[0].selector, "from");
+        assert_eq!(rules[1].selectors.len(), 2);
+        assert_eq!(rules[1].values[0], "20px");
+        assert!(rules[2].at_rule.is_none());
+        assert_eq!(rules[2].selectors[0].selector, "label");
+        assert_eq!(rules[2].values[0], "blue");
+    }
+
+    #[test]
+    fn media_at_rule() {
+        let input = "@media (prefers-reduced-motion: reduce) {\n    panel {\n        transition: none;\n    }\n}\nlabel {\n    color: blue;\n}\n";
+        let rules = Parser::new(Lexer::new(input).tokens()).parse();
+        assert_eq!(rules.len(), 2);
+        let Some(AtRule::Media(query)) = rules[0].at_rule else {
+            panic!("Expected media rule");
+        };
+        assert_eq!(query.reduced_motion, Some(true));
+        assert_eq!(rules[0].selectors[0].selector, "panel");
+        assert_eq!(rules[0].values[0], "none");
+        assert!(rules[1].at_rule.is_none());
+    }
+
+    #[test]
+    fn universal_selector() {
+        let s = Selector::try_from("*").unwrap();
+        assert_eq!(s.selector, UNIVERSAL_SELECTOR);
+        let s = Selector::try_from("*:hover").unwrap();
+        assert_eq!(s.selector, UNIVERSAL_SELECTOR);
+        let s = Selector::try_from(":focus-visible").unwrap();
+        assert_eq!(s.selector, UNIVERSAL_SELECTOR);
+        assert!(matches!(s.pseudo_class, Some(PseudoClass::FocusVisible)));
+        let s = Selector::try_from(":root").unwrap();
+        assert_eq!(s.selector, ROOT_SELECTOR);
+    }
 }
@@ -0,0 +1,176 @@
+use std::cell::Cell;
+
+/// Values that relative lengths are resolved against when styles are parsed.
+///
+/// Floem lengths are pixels, so `em`, `rem`, `vw` and `vh` are converted
+/// to px and styles have to be parsed again when these values change.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Units {
+    /// Font size of `rem`
+    pub root_font_size: f64,
+    /// Font size of `em`, font size set by the rule being parsed. Floem doesn't
+    /// expose inherited font size, so `em` is rejected without it.
+    pub font_size: Option<f64>,
+    pub viewport_width: f64,
+    pub viewport_height: f64,
+    /// Multiplier of font sizes
+    pub text_scale: f64,
+    /// Multiplier of other lengths
+    pub layout_scale: f64,
+}
+
+impl Units {
+    pub const DEFAULT: Self = Self {
+        root_font_size: 16.0,
+        font_size: None,
+        viewport_width: 800.0,
+        viewport_height: 600.0,
+        text_scale: 1.0,
+        layout_scale: 1.0,
+    };
+}
+
+impl Default for Units {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+thread_local! {
+    static UNITS: Cell<Units> = const { Cell::new(Units::DEFAULT) };
+    static VIEWPORT_USED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Units used by length parsers on this thread
+#[must_use]
+pub fn current() -> Units {
+    UNITS.with(Cell::get)
+}
+
+/// Sets units used by length parsers on this thread
+pub fn set(units: Units) {
+    UNITS.with(|u| u.set(units));
+}
+
+/// Whether viewport units were resolved on this thread since the last call.
+/// Styles without them don't have to be parsed again when the window is resized.
+pub fn take_viewport_used() -> bool {
+    VIEWPORT_USED.with(|v| v.replace(false))
+}
+
+/// Resolves css length to px multiplied by layout scale. Accepts `px`, `pt`,
+/// `em`, `rem`, `vw`, `vh`, `vmin`, `vmax` and unitless `0`. `em` needs `font_size`.
+#[must_use]
+pub fn parse_length(s: &str) -> Option<f64> {
+    let units = current();
+    resolve(s, units.font_size).map(|v| v * units.layout_scale)
+}
+
+/// Like `parse_length`, but multiplied by text scale. `em` is rejected since
+/// it is relative to the parent font size, which is not known when styles are parsed.
+#[must_use]
+pub fn parse_font_size(s: &str) -> Option<f64> {
+    let units = current();
+    resolve(s, None).map(|v| v * units.text_scale)
+}
+
+/// Like `parse_length`, but multiplied by text scale, for lengths that
+/// follow the size of text such as line height
+#[must_use]
+pub fn parse_text_length(s: &str) -> Option<f64> {
+    let units = current();
+    resolve(s, units.font_size).map(|v| v * units.text_scale)
+}
+
+fn resolve(s: &str, em: Option<f64>) -> Option<f64> {
+    if s == "0" {
+        return Some(0.0);
+    }
+    let units = current();
+    let (value, factor) = if let Some(v) = s.strip_suffix("px") {
+        (v, 1.0)
+    } else if let Some(v) = s.strip_suffix("pt") {
+        (v, 96.0 / 72.0)
+    } else if let Some(v) = s.strip_suffix("rem") {
+        (v, units.root_font_size)
+    } else if let Some(v) = s.strip_suffix("em") {
+        let Some(em) = em else {
+            log::warn!(
+                "'{s}' is relative to inherited font size that Floem doesn't expose, use rem"
+            );
+            return None;
+        };
+        (v, em)
+    } else if let Some(v) = s.strip_suffix("vw") {
+        (v, viewport_percent(units.viewport_width))
+    } else if let Some(v) = s.strip_suffix("vh") {
+        (v, viewport_percent(units.viewport_height))
+    } else if let Some(v) = s.strip_suffix("vmin") {
+        (
+            v,
+            viewport_percent(units.viewport_width.min(units.viewport_height)),
+        )
+    } else if let Some(v) = s.strip_suffix("vmax") {
+        (
+            v,
+            viewport_percent(units.viewport_width.max(units.viewport_height)),
+        )
+    } else {
+        return None;
+    };
+    value.trim_end().parse::<f64>().ok().map(|v| v * factor)
+}
+
+fn viewport_percent(px: f64) -> f64 {
+    VIEWPORT_USED.with(|v| v.set(true));
+    px / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        current, parse_font_size, parse_length, parse_text_length, set, take_viewport_used, Units,
+    };
+
+    #[test]
+    fn relative_lengths() {
+        set(Units {
+            root_font_size: 10.0,
+            font_size: Some(20.0),
+            viewport_width: 1000.0,
+            viewport_height: 500.0,
+            text_scale: 1.0,
+            layout_scale: 1.0,
+        });
+        assert_eq!(parse_length("12px"), Some(12.0));
+        assert_eq!(parse_length("0"), Some(0.0));
+        assert_eq!(parse_length("1.5rem"), Some(15.0));
+        assert_eq!(parse_length("2em"), Some(40.0));
+        assert_eq!(parse_font_size("2em"), None);
+        assert_eq!(parse_font_size("2rem"), Some(20.0));
+        assert!(!take_viewport_used());
+        assert_eq!(parse_length("10vw"), Some(100.0));
+        assert!(take_viewport_used());
+        assert!(!take_viewport_used());
+        assert_eq!(parse_length("10vh"), Some(50.0));
+        assert_eq!(parse_length("10vmax"), Some(100.0));
+        assert_eq!(parse_length("12pt"), Some(16.0));
+        assert_eq!(parse_length("12"), None);
+        assert_eq!(parse_length("em"), None);
+        set(Units {
+            text_scale: 1.5,
+            layout_scale: 2.0,
+            ..current()
+        });
+        assert_eq!(parse_font_size("1rem"), Some(15.0));
+        assert_eq!(parse_length("1rem"), Some(20.0));
+        assert_eq!(parse_text_length("1em"), Some(30.0));
+        set(Units {
+            font_size: None,
+            ..current()
+        });
+        assert_eq!(parse_length("1em"), None);
+        assert_eq!(parse_length("1rem"), Some(20.0));
+        set(Units::DEFAULT);
+    }
+}
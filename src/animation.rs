@@ -0,0 +1,85 @@
+use floem::animate::{AnimStateCommand, Animation};
+use floem::style::Style;
+use floem_css_parser::declaration::{
+    AnimationDelayProp, AnimationDirection, AnimationDirectionProp, AnimationDurationProp,
+    AnimationIterationCountProp, AnimationNameProp, AnimationTimingFunctionProp, IterationCount,
+};
+
+use crate::style::StyleMap;
+
+/// Keyframe ids are per mille so offsets like `33.3%` keep their place
+const MAX_FRAME: u16 = 1000;
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn frame_id(offset: f64, reversed: bool) -> u16 {
+    let offset = if reversed { 1.0 - offset } else { offset };
+    (offset * f64::from(MAX_FRAME)).round() as u16
+}
+
+/// Whether `class_str` resolves to animation that has `@keyframes`
+pub(crate) fn has_css_animation(map: &StyleMap, class_str: &str) -> bool {
+    map.apply_classes(Style::new(), class_str)
+        .get(AnimationNameProp)
+        .is_some_and(|name| map.keyframes(&name).is_some())
+}
+
+/// Builds animation from `animation-*` properties of `class_str` and matching `@keyframes`.
+/// Animation is stopped if classes have no animation.
+pub(crate) fn css_animation(map: &StyleMap, a: Animation, class_str: &str) -> Animation {
+    let style = map.apply_classes(Style::new(), class_str);
+    let Some(name) = style.get(AnimationNameProp) else {
+        return a.initial_state(AnimStateCommand::Stop);
+    };
+    let Some(frames) = map.keyframes(&name) else {
+        log::warn!("Animation '{name}' has no @keyframes");
+        return a.initial_state(AnimStateCommand::Stop);
+    };
+    let duration = style.get(AnimationDurationProp);
+    let iterations = style.get(AnimationIterationCountProp);
+    if duration.is_zero() || iterations == IterationCount::Count(0) {
+        return a.initial_state(AnimStateCommand::Stop);
+    }
+    let easing = style.get(AnimationTimingFunctionProp);
+    let direction = style.get(AnimationDirectionProp);
+    let reversed = matches!(
+        direction,
+        AnimationDirection::Reverse | AnimationDirection::AlternateReverse
+    );
+    // Floem plays auto reversed animation forth and back within the duration,
+    // so one iteration is played as is and other odd counts can't be played
+    let alternate = matches!(
+        direction,
+        AnimationDirection::Alternate | AnimationDirection::AlternateReverse
+    ) && iterations != IterationCount::Count(1);
+    if let (true, IterationCount::Count(n)) = (alternate, iterations) {
+        if n % 2 == 1 {
+            log::warn!("Floem can't alternate odd iteration count {n} of animation '{name}'");
+            return a.initial_state(AnimStateCommand::Stop);
+        }
+    }
+    let a = a
+        .max_key_frame(MAX_FRAME)
+        .duration(if alternate { duration * 2 } else { duration })
+        .delay(style.get(AnimationDelayProp))
+        .auto_reverse(alternate);
+    let a = match iterations {
+        IterationCount::Infinite => a.repeat(true),
+        IterationCount::Count(n) if alternate => a.repeat_times(n / 2),
+        IterationCount::Count(n) => a.repeat_times(n),
+    };
+    // Missing first and last frames use the element's own style like in css
+    let a = [0, MAX_FRAME]
+        .into_iter()
+        .filter(|id| !frames.iter().any(|(o, _)| frame_id(*o, reversed) == *id))
+        .fold(a, |a, id| {
+            let easing = easing.clone();
+            a.keyframe(id, move |f| f.computed_style().ease(easing.clone()))
+        });
+    frames.iter().fold(a, |a, (offset, frame)| {
+        let frame = frame.clone();
+        let easing = easing.clone();
+        a.keyframe(frame_id(*offset, reversed), move |f| {
+            f.style(|s| s.apply(frame.clone())).ease(easing.clone())
+        })
+    })
+}
@@ -0,0 +1,101 @@
+use floem::style::{Style, StyleClass};
+use floem::views::dropdown::DropdownClass;
+use floem::views::editor::{gutter::GutterClass, view::EditorViewClass};
+use floem::views::scroll::{Handle, ScrollClass, Track};
+use floem::views::slider::{AccentBarClass, BarClass, SliderClass};
+use floem::views::{
+    ButtonClass, CheckboxClass, LabelClass, LabeledCheckboxClass, LabeledRadioButtonClass,
+    ListClass, ListItemClass, PlaceholderTextClass, RadioButtonClass, RadioButtonDotClass,
+    SvgClass, TextInputClass, ToggleButtonClass, TooltipClass, TooltipContainerClass,
+};
+use smallvec::SmallVec;
+
+use crate::style::StyleMap;
+
+type Installer = fn(Style, Style) -> Style;
+
+/// Maps css selector names to Floem style classes.
+///
+/// Rules of registered selectors are installed globally on the view wrapped by
+/// `theme_provider`, so every built-in widget using the class gets styled without
+/// calling `.css()` on it.
+///
+/// Default registry contains Floem's built-in widget classes, named in kebab-case
+/// after the class type, e.g. `button-class` for `ButtonClass` and `scroll-handle-class`
+/// for scroll `Handle`.
+#[derive(Clone)]
+pub struct StyleClassRegistry {
+    names: SmallVec<[&'static str; 32]>,
+    installers: SmallVec<[Installer; 32]>,
+}
+
+impl StyleClassRegistry {
+    #[must_use]
+    pub const fn new_const() -> Self {
+        Self {
+            names: SmallVec::new_const(),
+            installers: SmallVec::new_const(),
+        }
+    }
+
+    /// Registers class `C` to be styled with rules of selector `name`
+    #[must_use]
+    pub fn with<C: StyleClass>(mut self, name: &'static str) -> Self {
+        self.register::<C>(name);
+        self
+    }
+
+    /// Registers class `C` to be styled with rules of selector `name`.
+    /// Replaces existing class if `name` is already registered.
+    pub fn register<C: StyleClass>(&mut self, name: &'static str) {
+        if let Some(idx) = self.names.iter().position(|n| *n == name) {
+            self.installers[idx] = install_class::<C>;
+        } else {
+            self.names.push(name);
+            self.installers.push(install_class::<C>);
+        }
+    }
+
+    /// Installs styles from `map` for every registered class
+    #[must_use]
+    pub fn apply_classes(&self, s: Style, map: &StyleMap) -> Style {
+        self.names
+            .iter()
+            .zip(&self.installers)
+            .fold(s, |s, (name, install)| s.apply_opt(map.get(name), install))
+    }
+}
+
+impl Default for StyleClassRegistry {
+    fn default() -> Self {
+        Self::new_const()
+            .with::<ButtonClass>("button-class")
+            .with::<LabelClass>("label-class")
+            .with::<TooltipClass>("tooltip-class")
+            .with::<TooltipContainerClass>("tooltip-container-class")
+            .with::<ListClass>("list-class")
+            .with::<ListItemClass>("list-item-class")
+            .with::<CheckboxClass>("checkbox-class")
+            .with::<LabeledCheckboxClass>("labeled-checkbox-class")
+            .with::<RadioButtonClass>("radio-button-class")
+            .with::<RadioButtonDotClass>("radio-button-dot-class")
+            .with::<LabeledRadioButtonClass>("labeled-radio-button-class")
+            .with::<ToggleButtonClass>("toggle-button-class")
+            .with::<SliderClass>("slider-class")
+            .with::<BarClass>("slider-bar-class")
+            .with::<AccentBarClass>("slider-accent-bar-class")
+            .with::<ScrollClass>("scroll-class")
+            .with::<Handle>("scroll-handle-class")
+            .with::<Track>("scroll-track-class")
+            .with::<TextInputClass>("text-input-class")
+            .with::<PlaceholderTextClass>("placeholder-text-class")
+            .with::<DropdownClass>("dropdown-class")
+            .with::<SvgClass>("svg-class")
+            .with::<EditorViewClass>("editor-class")
+            .with::<GutterClass>("editor-gutter-class")
+    }
+}
+
+fn install_class<C: StyleClass>(s: Style, over: Style) -> Style {
+    s.class(C::default(), |_| over)
+}
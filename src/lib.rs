@@ -10,11 +10,17 @@ mod provider;
 #[path = "provider_static.rs"]
 mod provider;
 
+mod animation;
+mod classes;
 mod error;
 mod options;
 mod parser;
 mod style;
 
+pub use classes::StyleClassRegistry;
+pub use floem_css_parser::logical::Direction;
+pub use floem_css_parser::media::MediaFeatures;
+pub use floem_css_parser::units::Units;
 pub use options::ProviderOptions;
 pub use provider::{theme_provider, StyleProvider};
 pub use style::{StyleCss, StyleMap};
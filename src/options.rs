@@ -1,5 +1,11 @@
 use std::path::PathBuf;
 
+use floem_css_parser::logical::Direction;
+use floem_css_parser::media::MediaFeatures;
+use floem_css_parser::units::Units;
+
+use crate::StyleClassRegistry;
+
 /// Options for initializing theme and responsive loader.
 ///
 /// `path` defaults to `./styles` if not defined. Automatically detects if given
@@ -7,9 +13,22 @@ use std::path::PathBuf;
 ///
 /// `recursive` sets the filesystem notifier mode to also detect changes in subfolders.
 /// Has no have effect if path points to file.
+///
+/// `classes` maps css selectors to Floem style classes that are styled globally.
+/// Defaults to Floem's built-in widget classes, see `StyleClassRegistry`.
+///
+/// `root_font_size` is the font size of `rem` units in px, defaults to 16.
+///
+/// `media_features` are the initial user preferences matched by `@media` rules.
+///
+/// `direction` resolves logical properties like `margin-inline-start`, defaults to left-to-right.
 pub struct ProviderOptions {
     pub path: PathBuf,
     pub recursive: bool,
+    pub classes: StyleClassRegistry,
+    pub root_font_size: f64,
+    pub media_features: MediaFeatures,
+    pub direction: Direction,
 }
 
 impl Default for ProviderOptions {
@@ -17,6 +36,10 @@ impl Default for ProviderOptions {
         Self {
             path: PathBuf::from("./styles"),
             recursive: true,
+            classes: StyleClassRegistry::default(),
+            root_font_size: Units::DEFAULT.root_font_size,
+            media_features: MediaFeatures::default(),
+            direction: Direction::default(),
         }
     }
 }
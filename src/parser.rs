@@ -1,118 +1,189 @@
+use std::borrow::Cow;
+
 use floem::{
-    style::{BorderRadius, SelectionCornerRadius, Style, StyleValue, TextColor},
+    peniko::Brush,
+    style::{Background, BorderRadius, SelectionCornerRadius, Style, StyleValue, TextColor},
     unit::PxPct,
     views::{editor::SelectionColor, PlaceholderTextClass},
 };
-use floem_css_parser::{css_to_rules, declaration::Declaration, PseudoClass, Rule};
+use floem_css_parser::{
+    css_to_rules,
+    declaration::{
+        is_unsupported, parse_keyframe_offset, unsupported_hint, CssWideKeyword, Declaration,
+    },
+    logical::{self, Direction},
+    media::{self, MediaFeatures},
+    units::{self, Units},
+    AtRule, PseudoClass, PseudoElement, Rule, Selector,
+};
 
 use crate::style::StyleMap;
 
 fn rule_to_style(value: &Rule) -> Style {
+    let base = units::current();
+    // `em` of other properties is relative to font size of the rule,
+    // font size inherited from parent views is not known here
+    let font_size = value
+        .iter_props()
+        .rev()
+        .filter(|(k, _)| *k == "font-size" || *k == "font")
+        .find_map(|kv| match Declaration::from_cow(kv)? {
+            Declaration::FontSize(px) => Some(px.0),
+            Declaration::Font(font) => Some(font.size.0),
+            _ => None,
+        })
+        .map(|px| px / base.text_scale);
+    units::set(Units { font_size, ..base });
     let mut style = Style::new();
     for kv in value.iter_props() {
-        if let Some(d) = Declaration::from_cow(kv) {
-            style = d.apply_style(style);
+        match logical::expand(kv.0, kv.1) {
+            Some(physical) => {
+                for (property, value) in physical {
+                    let kv = (&Cow::Owned(property), &Cow::Borrowed(value));
+                    style = apply_declaration(style, kv);
+                }
+            }
+            None => style = apply_declaration(style, kv),
         }
     }
+    units::set(base);
+    style
+}
+
+fn apply_declaration(style: Style, kv: (&Cow<'_, str>, &Cow<'_, str>)) -> Style {
+    let is_property = kv.0 == "all" || Declaration::PROPERTIES.contains(&kv.0.as_ref());
+    if let Some(keyword) = CssWideKeyword::parse(kv.1).filter(|_| is_property) {
+        return Declaration::apply_keyword(style, kv.0, keyword);
+    }
+    if let Some(d) = Declaration::from_cow(kv) {
+        return d.apply_style(style);
+    }
+    if let Some(hint) = unsupported_hint(kv.0).filter(|_| is_unsupported(kv.0)) {
+        log::warn!("Property '{}' is not supported, {hint}", kv.0);
+    } else if is_unsupported(kv.0) {
+        log::warn!("Property '{}' is not supported by Floem", kv.0);
+    } else if is_property {
+        log::warn!("Invalid value '{}' for property '{}'", kv.1, kv.0);
+    } else {
+        log::warn!("Unknown property '{}'", kv.0);
+    }
     style
 }
 
 #[must_use]
 pub fn parse_rules(rules: &[Rule]) -> StyleMap {
     let mut map = StyleMap::new_const();
+    let features = media::current();
     for rule in rules {
+        if let Some(AtRule::Media(query)) = rule.at_rule {
+            if !query.matches(features) {
+                continue;
+            }
+        }
         let style = rule_to_style(rule);
+        if let Some(AtRule::Keyframes(name)) = rule.at_rule {
+            for selector in &rule.selectors {
+                match parse_keyframe_offset(selector.selector) {
+                    Some(offset) => map.insert_keyframe(name, offset, style.clone()),
+                    None => log::warn!(
+                        "Invalid keyframe selector '{}' in @keyframes {name}",
+                        selector.selector
+                    ),
+                }
+            }
+            continue;
+        }
         for selector in &rule.selectors {
             let selector_style = style.clone();
             let to_modify = map.remove(selector.selector).unwrap_or_default();
-            let modified = modify_selector(&selector.pseudo_class, to_modify, selector_style);
+            let modified = modify_selector(selector, to_modify, selector_style);
             map.insert(selector.selector, modified);
         }
     }
     map
 }
 
-fn modify_selector(
-    pseudo_class: &Option<PseudoClass>,
+fn modify_selector(selector: &Selector, to_modify: Style, selector_style: Style) -> Style {
+    match selector.pseudo_element {
+        None => apply_pseudo(selector.pseudo_class, to_modify, selector_style),
+        Some(PseudoElement::Placeholder) => to_modify.class(PlaceholderTextClass, |s| {
+            apply_pseudo(selector.pseudo_class, s, selector_style)
+        }),
+        Some(PseudoElement::Selection) => apply_pseudo(
+            selector.pseudo_class,
+            to_modify,
+            selection_style(&selector_style),
+        ),
+    }
+}
+
+fn apply_pseudo(
+    pseudo_class: Option<PseudoClass>,
     to_modify: Style,
     selector_style: Style,
 ) -> Style {
-    if pseudo_class.is_none() {
+    let Some(pseudo_class) = pseudo_class else {
         return to_modify.apply(selector_style);
-    }
-    let pseudo_class = pseudo_class.unwrap();
-    apply_pseudo(pseudo_class, to_modify, selector_style)
-}
-
-#[cold]
-fn apply_pseudo(pseudo_class: PseudoClass, to_modify: Style, selector_style: Style) -> Style {
+    };
     match pseudo_class {
         PseudoClass::Hover => to_modify.hover(|_| selector_style),
         PseudoClass::Focus => to_modify.focus(|_| selector_style),
         PseudoClass::FocusHover => to_modify.focus(|s| s.hover(|_| selector_style)),
+        PseudoClass::FocusVisible => to_modify.focus_visible(|_| selector_style),
+        PseudoClass::Selected => to_modify.selected(|_| selector_style),
+        PseudoClass::SelectedHover => to_modify.selected(|s| s.hover(|_| selector_style)),
         PseudoClass::Active => to_modify.active(|_| selector_style),
         PseudoClass::ActiveHover => to_modify.active(|s| s.hover(|_| selector_style)),
         PseudoClass::Disabled => to_modify.disabled(|_| selector_style),
         PseudoClass::DisabledHover => to_modify.disabled(|s| s.hover(|_| selector_style)),
-        PseudoClass::Placeholder => {
-            to_modify.class(PlaceholderTextClass, |s| s.apply(selector_style))
-        }
-        PseudoClass::Selection => {
-            // TODO Maybe ugly maybe not
-            if let StyleValue::Val(PxPct::Px(radius)) = selector_style.get_style_value(BorderRadius)
-            {
-                return to_modify.set_style_value(SelectionCornerRadius, radius.into());
-            }
-            if let StyleValue::Val(Some(color)) = selector_style.get_style_value(TextColor) {
-                return to_modify.set_style_value(SelectionColor, color.into());
-            }
-            to_modify
+    }
+}
+
+/// Maps `::selection` declarations to selection props.
+///
+/// Floem can't paint selected text with a different color, so `color` is used
+/// as the selection color when `background-color` is not set.
+#[cold]
+fn selection_style(selector_style: &Style) -> Style {
+    let mut style = Style::new();
+    match selector_style.get_style_value(BorderRadius) {
+        StyleValue::Val(PxPct::Px(radius)) => style = style.set(SelectionCornerRadius, radius),
+        StyleValue::Val(PxPct::Pct(_)) => {
+            log::warn!("::selection border-radius supports only px values");
         }
+        _ => (),
     }
+    let background = match selector_style.get_style_value(Background) {
+        StyleValue::Val(Some(Brush::Solid(color))) => Some(color),
+        _ => None,
+    };
+    let text = match selector_style.get_style_value(TextColor) {
+        StyleValue::Val(color) => color,
+        _ => None,
+    };
+    if let Some(color) = background.or(text) {
+        style = style.set(SelectionColor, color);
+    }
+    style
 }
 
+/// Parses styles with relative lengths resolved against `units`, `@media` rules
+/// matched against `features` and logical properties resolved with `direction`
 #[must_use]
-pub fn parse_css(input: &str) -> StyleMap {
+pub fn parse_css(
+    input: &str,
+    units: Units,
+    features: MediaFeatures,
+    direction: Direction,
+) -> StyleMap {
     let now = std::time::SystemTime::now();
 
+    units::set(units);
+    media::set(features);
+    logical::set(direction);
+
     let rules = css_to_rules(input);
-    let mut map = StyleMap::new_const();
-    for rule in &rules {
-        let style = rule_to_style(rule);
-        for selector in &rule.selectors {
-            let selector_style = style.clone();
-            let mut to_modify = map.remove(selector.selector).unwrap_or_default();
-            let to_insert = match selector.pseudo_class {
-                Some(PseudoClass::Hover) => to_modify.hover(|_| selector_style),
-                Some(PseudoClass::Focus) => to_modify.focus(|_| selector_style),
-                Some(PseudoClass::FocusHover) => to_modify.focus(|s| s.hover(|_| selector_style)),
-                Some(PseudoClass::Active) => to_modify.active(|_| selector_style),
-                Some(PseudoClass::ActiveHover) => to_modify.active(|s| s.hover(|_| selector_style)),
-                Some(PseudoClass::Disabled) => to_modify.disabled(|_| selector_style),
-                Some(PseudoClass::DisabledHover) => {
-                    to_modify.disabled(|s| s.hover(|_| selector_style))
-                }
-                Some(PseudoClass::Placeholder) => {
-                    to_modify.class(PlaceholderTextClass, |s| s.apply(selector_style))
-                }
-                Some(PseudoClass::Selection) => {
-                    // TODO Maybe ugly maybe not
-                    if let StyleValue::Val(PxPct::Px(radius)) =
-                        selector_style.get_style_value(BorderRadius)
-                    {
-                        to_modify = to_modify.set_style_value(SelectionCornerRadius, radius.into());
-                    }
-                    if let StyleValue::Val(Some(color)) = selector_style.get_style_value(TextColor)
-                    {
-                        to_modify = to_modify.set_style_value(SelectionColor, color.into());
-                    }
-                    to_modify
-                }
-                None => to_modify.apply(selector_style),
-            };
-            map.insert(selector.selector, to_insert);
-        }
-    }
+    let map = parse_rules(&rules);
     {
         let elaps = std::time::SystemTime::now()
             .duration_since(now)
@@ -1,10 +1,18 @@
+use std::cell::{Cell, RefCell};
 use std::path::PathBuf;
 use std::rc::Rc;
 
 use crossbeam_channel::{Receiver, Sender};
+use floem::event::{Event, EventListener};
 use floem::ext_event::create_signal_from_channel;
-use floem::reactive::{create_effect, provide_context, RwSignal, SignalGet, SignalUpdate};
+use floem::reactive::{
+    create_effect, provide_context, RwSignal, SignalGet, SignalUpdate, SignalWith,
+};
+use floem::views::Decorators;
 use floem::IntoView;
+use floem_css_parser::logical::Direction;
+use floem_css_parser::media::MediaFeatures;
+use floem_css_parser::units::{self, Units};
 
 use crate::error::ThemeError;
 use crate::observer::FileObserver;
@@ -16,6 +24,13 @@ pub struct StyleProvider {
     path: PathBuf,
     channel: (Sender<()>, Receiver<()>),
     pub(crate) map: RwSignal<StyleMap>,
+    /// Last read styles, parsed again when `units` or `media_features` change
+    source: RefCell<String>,
+    units: RwSignal<Units>,
+    media_features: RwSignal<MediaFeatures>,
+    direction: RwSignal<Direction>,
+    /// Whether last parsed styles have viewport units
+    uses_viewport: Cell<bool>,
     #[allow(unused)]
     observer: FileObserver,
 }
@@ -39,14 +54,95 @@ impl StyleProvider {
         std::thread::spawn(move || {
             floem_css_parser::analyze(&s);
         });
-        let new_map = parse_css(&styles_str);
+        *self.source.borrow_mut() = styles_str;
+        self.rebuild();
+        Ok(())
+    }
+
+    fn rebuild(&self) {
+        let new_map = parse_css(
+            &self.source.borrow(),
+            self.units.get_untracked(),
+            self.media_features.get_untracked(),
+            self.direction.get_untracked(),
+        );
+        self.uses_viewport.set(units::take_viewport_used());
         if new_map.is_empty() {
             log::warn!("Styles parsed but no styles found");
         }
         self.map.update(|map| {
             let _ = std::mem::replace(map, new_map);
         });
-        Ok(())
+    }
+
+    /// Styles without viewport units are kept when only the window size changes
+    fn units_changed(&self, prev: Units, current: Units) -> bool {
+        if self.uses_viewport.get() {
+            return prev != current;
+        }
+        let prev = Units {
+            viewport_width: current.viewport_width,
+            viewport_height: current.viewport_height,
+            ..prev
+        };
+        prev != current
+    }
+
+    /// Sets font size of `rem` units, styles are resolved again with new size
+    pub fn set_root_font_size(&self, px: f64) {
+        self.units.update(|u| u.root_font_size = px);
+    }
+
+    /// Multiplies font sizes, styles are resolved again with new scale
+    pub fn set_text_scale(&self, scale: f64) {
+        self.units.update(|u| u.text_scale = scale);
+    }
+
+    /// Multiplies lengths other than font sizes, styles are resolved again with new scale
+    pub fn set_layout_scale(&self, scale: f64) {
+        self.units.update(|u| u.layout_scale = scale);
+    }
+
+    /// Sets both text and layout scale
+    pub fn set_scale(&self, scale: f64) {
+        self.units.update(|u| {
+            u.text_scale = scale;
+            u.layout_scale = scale;
+        });
+    }
+
+    /// Values used to resolve relative units
+    #[must_use]
+    pub fn units(&self) -> Units {
+        self.units.get_untracked()
+    }
+
+    /// Sets `prefers-reduced-motion`, disables transitions and animations when `true`
+    pub fn set_reduced_motion(&self, reduced_motion: bool) {
+        self.media_features
+            .update(|f| f.reduced_motion = reduced_motion);
+    }
+
+    /// Sets `prefers-contrast: more`
+    pub fn set_more_contrast(&self, more_contrast: bool) {
+        self.media_features
+            .update(|f| f.more_contrast = more_contrast);
+    }
+
+    /// Preferences matched by `@media` rules
+    #[must_use]
+    pub fn media_features(&self) -> MediaFeatures {
+        self.media_features.get_untracked()
+    }
+
+    /// Sets direction of logical properties, styles are resolved again with new direction
+    pub fn set_direction(&self, direction: Direction) {
+        self.direction.set(direction);
+    }
+
+    #[must_use]
+    pub fn direction(&self) -> Direction {
+        self.direction.get_untracked()
     }
 }
 
@@ -60,6 +156,14 @@ impl TryFrom<ProviderOptions> for StyleProvider {
             observer,
             channel,
             map: RwSignal::new(StyleMap::new_const()),
+            source: RefCell::default(),
+            units: RwSignal::new(Units {
+                root_font_size: options.root_font_size,
+                ..Units::default()
+            }),
+            media_features: RwSignal::new(options.media_features),
+            direction: RwSignal::new(options.direction),
+            uses_viewport: Cell::new(false),
         };
         Ok(theme)
     }
@@ -106,19 +210,28 @@ impl TryFrom<ProviderOptions> for StyleProvider {
 /// }
 /// ```
 ///
+/// Returns `child` converted to a view, since class styles of built-in widgets are set on it.
+///
 /// # Panics
 ///
 /// Panics if options path doesn't exist in filesystem or is otherwise unreadable
-pub fn theme_provider<V, F>(child: F, options: ProviderOptions) -> V
+#[allow(clippy::needless_doctest_main)]
+pub fn theme_provider<V, F>(child: F, options: ProviderOptions) -> V::V
 where
     F: Fn() -> V,
     V: IntoView + 'static,
 {
+    let classes = options.classes.clone();
     let theme = StyleProvider::new(options).expect("Invalid theme path");
     theme.reload().expect("Cannot load theme");
     let observer_event = create_signal_from_channel(theme.channel.1.clone());
+    let map = theme.map;
+    let units = theme.units;
+    let media_features = theme.media_features;
+    let direction = theme.direction;
     let rc_theme = Rc::new(theme);
     provide_context(rc_theme.clone());
+    let units_theme = rc_theme.clone();
     create_effect(move |_| {
         if observer_event.get().is_some() {
             if let Err(e) = rc_theme.reload() {
@@ -126,5 +239,23 @@ where
             }
         }
     });
+    create_effect(move |prev: Option<(Units, MediaFeatures, Direction)>| {
+        let current = (units.get(), media_features.get(), direction.get());
+        if prev.is_some_and(|(u, f, d)| {
+            units_theme.units_changed(u, current.0) || (f, d) != (current.1, current.2)
+        }) {
+            units_theme.rebuild();
+        }
+        current
+    });
     child()
+        .style(move |s| map.with(|m| classes.apply_classes(s, m)))
+        .on_event_cont(EventListener::WindowResized, move |e| {
+            if let Event::WindowResized(size) = e {
+                units.update(|u| {
+                    u.viewport_width = size.width;
+                    u.viewport_height = size.height;
+                });
+            }
+        })
 }
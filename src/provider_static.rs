@@ -1,5 +1,14 @@
-use floem::reactive::{provide_context, RwSignal, SignalUpdate};
+use std::cell::Cell;
+
+use floem::event::{Event, EventListener};
+use floem::reactive::{
+    create_effect, provide_context, RwSignal, SignalGet, SignalUpdate, SignalWith,
+};
+use floem::views::Decorators;
 use floem::IntoView;
+use floem_css_parser::logical::Direction;
+use floem_css_parser::media::MediaFeatures;
+use floem_css_parser::units::{self, Units};
 
 use crate::error::ThemeError;
 use crate::parser::parse_css;
@@ -8,6 +17,11 @@ use crate::ProviderOptions;
 
 pub struct StyleProvider {
     pub(crate) map: RwSignal<StyleMap>,
+    units: RwSignal<Units>,
+    media_features: RwSignal<MediaFeatures>,
+    direction: RwSignal<Direction>,
+    /// Whether parsed styles have viewport units
+    uses_viewport: Cell<bool>,
 }
 
 impl StyleProvider {
@@ -17,6 +31,10 @@ impl StyleProvider {
     pub fn new() -> Result<Self, ThemeError> {
         let theme = Self {
             map: RwSignal::new(StyleMap::new_const()),
+            units: RwSignal::new(Units::default()),
+            media_features: RwSignal::new(MediaFeatures::default()),
+            direction: RwSignal::new(Direction::default()),
+            uses_viewport: Cell::new(false),
         };
         Ok(theme)
     }
@@ -28,7 +46,13 @@ impl StyleProvider {
     /// Panics only in debug mode if time is flowing into wrong direction
     pub fn reload(&self) -> Result<(), ThemeError> {
         let styles_str = include_str!(concat!(env!("OUT_DIR"), "/style.css"));
-        let parsed_styles = parse_css(&styles_str);
+        let parsed_styles = parse_css(
+            styles_str,
+            self.units.get_untracked(),
+            self.media_features.get_untracked(),
+            self.direction.get_untracked(),
+        );
+        self.uses_viewport.set(units::take_viewport_used());
         if parsed_styles.is_empty() {
             log::warn!("Styles parsed but no styles found");
         }
@@ -38,20 +62,123 @@ impl StyleProvider {
         });
         Ok(())
     }
+
+    /// Styles without viewport units are kept when only the window size changes
+    fn units_changed(&self, prev: Units, current: Units) -> bool {
+        if self.uses_viewport.get() {
+            return prev != current;
+        }
+        let prev = Units {
+            viewport_width: current.viewport_width,
+            viewport_height: current.viewport_height,
+            ..prev
+        };
+        prev != current
+    }
+
+    /// Sets font size of `rem` units, styles are resolved again with new size
+    pub fn set_root_font_size(&self, px: f64) {
+        self.units.update(|u| u.root_font_size = px);
+    }
+
+    /// Multiplies font sizes, styles are resolved again with new scale
+    pub fn set_text_scale(&self, scale: f64) {
+        self.units.update(|u| u.text_scale = scale);
+    }
+
+    /// Multiplies lengths other than font sizes, styles are resolved again with new scale
+    pub fn set_layout_scale(&self, scale: f64) {
+        self.units.update(|u| u.layout_scale = scale);
+    }
+
+    /// Sets both text and layout scale
+    pub fn set_scale(&self, scale: f64) {
+        self.units.update(|u| {
+            u.text_scale = scale;
+            u.layout_scale = scale;
+        });
+    }
+
+    /// Values used to resolve relative units
+    #[must_use]
+    pub fn units(&self) -> Units {
+        self.units.get_untracked()
+    }
+
+    /// Sets `prefers-reduced-motion`, disables transitions and animations when `true`
+    pub fn set_reduced_motion(&self, reduced_motion: bool) {
+        self.media_features
+            .update(|f| f.reduced_motion = reduced_motion);
+    }
+
+    /// Sets `prefers-contrast: more`
+    pub fn set_more_contrast(&self, more_contrast: bool) {
+        self.media_features
+            .update(|f| f.more_contrast = more_contrast);
+    }
+
+    /// Preferences matched by `@media` rules
+    #[must_use]
+    pub fn media_features(&self) -> MediaFeatures {
+        self.media_features.get_untracked()
+    }
+
+    /// Sets direction of logical properties, styles are resolved again with new direction
+    pub fn set_direction(&self, direction: Direction) {
+        self.direction.set(direction);
+    }
+
+    #[must_use]
+    pub fn direction(&self) -> Direction {
+        self.direction.get_untracked()
+    }
 }
 
 /// Provides `Theme` as context
+///
+/// Returns `child` converted to a view, since class styles of built-in widgets are set on it.
+///
 /// # Panics
 ///
 /// Panics if path doesn't point to a existing folder.
-pub fn theme_provider<V, F>(child: F, _: ProviderOptions) -> V
+pub fn theme_provider<V, F>(child: F, options: ProviderOptions) -> V::V
 where
     F: Fn() -> V,
     V: IntoView + 'static,
 {
+    let classes = options.classes;
     let theme = StyleProvider::new().expect("Failed to create provider");
+    theme
+        .units
+        .update(|u| u.root_font_size = options.root_font_size);
+    theme.media_features.set(options.media_features);
+    theme.direction.set(options.direction);
     theme.reload().expect("Cannot load theme");
+    let map = theme.map;
+    let units = theme.units;
+    let media_features = theme.media_features;
+    let direction = theme.direction;
     let rc_theme = std::rc::Rc::new(theme);
-    provide_context(rc_theme);
+    provide_context(rc_theme.clone());
+    create_effect(move |prev: Option<(Units, MediaFeatures, Direction)>| {
+        let current = (units.get(), media_features.get(), direction.get());
+        if prev.is_some_and(|(u, f, d)| {
+            rc_theme.units_changed(u, current.0) || (f, d) != (current.1, current.2)
+        }) {
+            if let Err(e) = rc_theme.reload() {
+                log::error!("Cannot reload theme: {e}");
+            }
+        }
+        current
+    });
     child()
+        .style(move |s| map.with(|m| classes.apply_classes(s, m)))
+        .on_event_cont(EventListener::WindowResized, move |e| {
+            if let Event::WindowResized(size) = e {
+                units.update(|u| {
+                    u.viewport_width = size.width;
+                    u.viewport_height = size.height;
+                });
+            }
+        })
 }
@@ -4,9 +4,10 @@ use floem::reactive::{use_context, SignalWith};
 use floem::style::Style;
 use floem::views::Decorators;
 use floem::IntoView;
-use floem_css_parser::css_to_rules;
+use floem_css_parser::{css_to_rules, ROOT_SELECTOR, UNIVERSAL_SELECTOR};
 use smallvec::SmallVec;
 
+use crate::animation::{css_animation, has_css_animation};
 use crate::parser::parse_rules;
 use crate::StyleProvider;
 
@@ -19,18 +20,34 @@ impl<V> StyleCss for V
 where
     V: IntoView + 'static,
 {
+    /// Animation is attached only if `keys` have one when the view is created
+    ///
     /// # Panics
     /// Panics at compile time if `RwSignal<Theme>` context is not provided
     fn css(self, keys: &'static str) -> <Self as IntoView>::V {
         let theme = use_context::<Rc<StyleProvider>>().unwrap();
-        self.style(move |s| theme.map.with(|t| t.apply_classes(s, keys)))
-            .debug_name(keys)
+        let map = theme.map;
+        let view = self
+            .style(move |s| theme.map.with(|t| t.apply_classes(s, keys)))
+            .debug_name(keys);
+        if map.with_untracked(|t| has_css_animation(t, keys)) {
+            view.animation(move |a| map.with(|t| css_animation(t, a, keys)))
+        } else {
+            view
+        }
     }
 }
 
 pub struct StyleMap {
     keys: SmallVec<[String; 32]>,
     styles: SmallVec<[Style; 32]>,
+    keyframes: SmallVec<[Keyframes; 4]>,
+}
+
+/// Frames of `@keyframes` rule as offsets between 0 and 1 and their styles
+struct Keyframes {
+    name: String,
+    frames: SmallVec<[(f64, Style); 4]>,
 }
 
 impl StyleMap {
@@ -44,6 +61,7 @@ impl StyleMap {
         Self {
             keys: SmallVec::new_const(),
             styles: SmallVec::new_const(),
+            keyframes: SmallVec::new_const(),
         }
     }
 
@@ -55,12 +73,41 @@ impl StyleMap {
     }
 
     pub fn is_empty(&self) -> bool {
-        self.keys.is_empty()
+        self.keys.is_empty() && self.keyframes.is_empty()
     }
 
     pub fn clear(&mut self) {
         self.keys.clear();
         self.styles.clear();
+        self.keyframes.clear();
+    }
+
+    /// Adds frame to `@keyframes name`, replacing frame with same offset
+    pub fn insert_keyframe(&mut self, name: &str, offset: f64, style: Style) {
+        let idx = self
+            .keyframes
+            .iter()
+            .position(|k| k.name == name)
+            .unwrap_or_else(|| {
+                self.keyframes.push(Keyframes {
+                    name: name.to_string(),
+                    frames: SmallVec::new(),
+                });
+                self.keyframes.len() - 1
+            });
+        let frames = &mut self.keyframes[idx].frames;
+        match frames.iter_mut().find(|(o, _)| *o == offset) {
+            Some((_, frame)) => *frame = frame.clone().apply(style),
+            None => frames.push((offset, style)),
+        }
+    }
+
+    /// Frames of `@keyframes name`
+    pub fn keyframes(&self, name: &str) -> Option<&[(f64, Style)]> {
+        self.keyframes
+            .iter()
+            .find(|k| k.name == name)
+            .map(|k| k.frames.as_slice())
     }
 
     pub fn insert(&mut self, key: &str, style: Style) {
@@ -77,10 +124,13 @@ impl StyleMap {
 }
 
 impl StyleMap {
+    /// Applies global `*` and `:root` styles first and then
+    /// styles for each class in `class_str`
     #[must_use]
     pub fn apply_classes(&self, s: Style, class_str: &str) -> Style {
-        class_str
-            .split_whitespace()
+        [UNIVERSAL_SELECTOR, ROOT_SELECTOR]
+            .into_iter()
+            .chain(class_str.split_whitespace())
             .fold(s, |s, key| s.apply_opt(self.get(key), Style::apply))
     }
 }